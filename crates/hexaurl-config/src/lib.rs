@@ -20,6 +20,17 @@ pub enum ConfigError {
         /// Effective maximum length.
         max: usize,
     },
+    /// Delimiter rules allow a delimiter that the composition forbids.
+    InconsistentDelimiterRules {
+        /// Provided composition.
+        composition: Composition,
+        /// Delimiter allowed by the rules but forbidden by the composition.
+        delimiter: char,
+    },
+    /// Segment rule delimiter is neither a hyphen nor an underscore.
+    InvalidSegmentDelimiter(char),
+    /// Unified delimiter is neither a hyphen nor an underscore.
+    InvalidUnifiedDelimiter(char),
 }
 
 impl fmt::Display for ConfigError {
@@ -37,12 +48,48 @@ impl fmt::Display for ConfigError {
                     "Minimum length {min} cannot be greater than compiled maximum length {max}"
                 )
             }
+            Self::InconsistentDelimiterRules {
+                composition,
+                delimiter,
+            } => {
+                write!(
+                    f,
+                    "Delimiter rules allow '{delimiter}' but composition {composition:?} forbids it"
+                )
+            }
+            Self::InvalidSegmentDelimiter(delimiter) => {
+                write!(
+                    f,
+                    "Segment delimiter '{delimiter}' must be a hyphen or an underscore"
+                )
+            }
+            Self::InvalidUnifiedDelimiter(delimiter) => {
+                write!(
+                    f,
+                    "Unified delimiter '{delimiter}' must be a hyphen or an underscore"
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for ConfigError {}
 
+/// Error returned when parsing a [`Composition`] from an unrecognized string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseCompositionError;
+
+impl fmt::Display for ParseCompositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(
+            "Composition must be one of \"alphanumeric\", \"alphanumeric-hyphen\", \
+             \"alphanumeric-underscore\" or \"alphanumeric-hyphen-underscore\"",
+        )
+    }
+}
+
+impl std::error::Error for ParseCompositionError {}
+
 #[inline(always)]
 const fn calc_str_len(n: usize) -> usize {
     n * 4 / 3
@@ -61,6 +108,36 @@ fn validate_length_range(
     Ok(())
 }
 
+#[inline]
+fn validate_delimiter_consistency(
+    composition: Composition,
+    rules: DelimiterRules,
+) -> Result<(), ConfigError> {
+    let (allow_hyphen, allow_underscore) = composition.allowed_delimiters();
+    let uses_hyphen = rules.allow_leading_hyphens()
+        || rules.allow_trailing_hyphens()
+        || rules.max_hyphen_run() != Some(1)
+        || rules.allow_adjacent_hyphen_underscore();
+    let uses_underscore = rules.allow_leading_underscores()
+        || rules.allow_trailing_underscores()
+        || rules.max_underscore_run() != Some(1)
+        || rules.allow_adjacent_hyphen_underscore();
+
+    if uses_hyphen && !allow_hyphen {
+        return Err(ConfigError::InconsistentDelimiterRules {
+            composition,
+            delimiter: '-',
+        });
+    }
+    if uses_underscore && !allow_underscore {
+        return Err(ConfigError::InconsistentDelimiterRules {
+            composition,
+            delimiter: '_',
+        });
+    }
+    Ok(())
+}
+
 /// Precompiled validation configuration for a specific HexaURL byte size `N`.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct Config<const N: usize> {
@@ -68,6 +145,11 @@ pub struct Config<const N: usize> {
     effective_max: usize,
     composition: Composition,
     delimiter_rules: DelimiterRules,
+    segment_rule: Option<SegmentRule>,
+    unified_delimiter: Option<char>,
+    min_distinct_chars: Option<usize>,
+    reserved: &'static [&'static str],
+    trim_whitespace: bool,
     allow_hyphen: bool,
     allow_underscore: bool,
     needs_delimiter_pass: bool,
@@ -95,11 +177,24 @@ impl<const N: usize> Config<N> {
         self.min_length
     }
 
+    /// Returns the minimum allowed length, or `0` if there is none.
+    pub fn min_length_or_zero(&self) -> usize {
+        self.min_length.unwrap_or(0)
+    }
+
     /// Returns the effective maximum allowed length.
     pub fn effective_max(&self) -> usize {
         self.effective_max
     }
 
+    /// Returns the effective maximum length for a value of `M` bytes: the smaller of
+    /// [`effective_max`](Self::effective_max) and the capacity of `M`.
+    ///
+    /// Useful when a value validated by this config is resized to another byte size.
+    pub fn effective_max_for_bytes<const M: usize>(&self) -> usize {
+        core::cmp::min(self.effective_max, calc_str_len(M))
+    }
+
     /// Returns the identifier composition rule.
     pub fn composition(&self) -> Composition {
         self.composition
@@ -110,6 +205,36 @@ impl<const N: usize> Config<N> {
         self.delimiter_rules
     }
 
+    /// Returns a reference to the delimiter rules, without copying them.
+    pub fn delimiter_rules_ref(&self) -> &DelimiterRules {
+        &self.delimiter_rules
+    }
+
+    /// Returns the segment rule, if any.
+    pub fn segment_rule(&self) -> Option<SegmentRule> {
+        self.segment_rule
+    }
+
+    /// Returns the delimiter that both hyphens and underscores are rewritten to, if any.
+    pub fn unified_delimiter(&self) -> Option<char> {
+        self.unified_delimiter
+    }
+
+    /// Returns the minimum number of distinct characters, if any.
+    pub fn min_distinct_chars(&self) -> Option<usize> {
+        self.min_distinct_chars
+    }
+
+    /// Returns the reserved identifiers; empty if none are reserved.
+    pub fn reserved(&self) -> &'static [&'static str] {
+        self.reserved
+    }
+
+    /// Whether leading and trailing ASCII whitespace is trimmed before validation.
+    pub fn trim_whitespace(&self) -> bool {
+        self.trim_whitespace
+    }
+
     /// Whether hyphen is allowed by composition.
     pub fn allow_hyphen(&self) -> bool {
         self.allow_hyphen
@@ -124,6 +249,54 @@ impl<const N: usize> Config<N> {
     pub fn needs_delimiter_pass(&self) -> bool {
         self.needs_delimiter_pass
     }
+
+    /// Returns a copy with the minimum length set to `min`.
+    ///
+    /// Fails if `min` exceeds the effective maximum length.
+    pub fn with_min_length(self, min: usize) -> Result<Self, ConfigError> {
+        self.rebuild(Some(min), Some(self.effective_max))
+    }
+
+    /// Returns a copy without a minimum length.
+    pub fn without_min_length(self) -> Self {
+        Self {
+            min_length: None,
+            ..self
+        }
+    }
+
+    /// Returns a copy with the maximum length set to `max`, clamped to the capacity of `N`.
+    ///
+    /// Fails if the current minimum length exceeds `max`.
+    pub fn with_max_length(self, max: usize) -> Result<Self, ConfigError> {
+        self.rebuild(self.min_length, Some(max))
+    }
+
+    /// Returns a copy whose maximum length is the capacity of `N`.
+    pub fn without_max_length(self) -> Self {
+        Self {
+            effective_max: calc_str_len(N),
+            ..self
+        }
+    }
+
+    fn rebuild(
+        self,
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+    ) -> Result<Self, ConfigError> {
+        ConfigBuilder::new()
+            .min_length(min_length)
+            .max_length(max_length)
+            .composition(self.composition)
+            .delimiter(Some(self.delimiter_rules))
+            .segments(self.segment_rule)
+            .unify_delimiters(self.unified_delimiter)
+            .min_distinct_chars(self.min_distinct_chars)
+            .reserved(self.reserved)
+            .trim_whitespace(self.trim_whitespace)
+            .build()
+    }
 }
 
 impl<const N: usize> Default for Config<N> {
@@ -135,11 +308,19 @@ impl<const N: usize> Default for Config<N> {
 }
 
 /// Builder for compiled [`Config`].
+///
+/// The builder is [`Clone`], so a shared base can be forked into several related configs.
+#[derive(Clone)]
 pub struct ConfigBuilder<const N: usize> {
     min_length: Option<usize>,
     max_length: Option<usize>,
     composition: Composition,
     delimiter: Option<DelimiterRules>,
+    segments: Option<SegmentRule>,
+    unify_delimiters: Option<char>,
+    min_distinct_chars: Option<usize>,
+    reserved: &'static [&'static str],
+    trim_whitespace: bool,
 }
 
 impl<const N: usize> Default for ConfigBuilder<N> {
@@ -149,6 +330,11 @@ impl<const N: usize> Default for ConfigBuilder<N> {
             max_length: None,
             composition: Composition::default(),
             delimiter: None,
+            segments: None,
+            unify_delimiters: None,
+            min_distinct_chars: None,
+            reserved: &[],
+            trim_whitespace: false,
         }
     }
 }
@@ -171,6 +357,26 @@ impl<const N: usize> ConfigBuilder<N> {
         self
     }
 
+    /// Sets the minimum allowed length to `min`.
+    pub fn with_min_length(self, min: usize) -> Self {
+        self.min_length(Some(min))
+    }
+
+    /// Disables the minimum length check.
+    pub fn without_min_length(self) -> Self {
+        self.min_length(None)
+    }
+
+    /// Sets the maximum allowed length to `max`.
+    pub fn with_max_length(self, max: usize) -> Self {
+        self.max_length(Some(max))
+    }
+
+    /// Uses the capacity-derived maximum length.
+    pub fn without_max_length(self) -> Self {
+        self.max_length(None)
+    }
+
     /// Sets the identifier composition.
     pub fn composition(mut self, composition: Composition) -> Self {
         self.composition = composition;
@@ -183,6 +389,72 @@ impl<const N: usize> ConfigBuilder<N> {
         self
     }
 
+    /// Sets the segment rule.
+    pub fn segments(mut self, segments: Option<SegmentRule>) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// Treats hyphens and underscores as the same delimiter.
+    ///
+    /// With `Some('-')`, underscores are rewritten to hyphens before validation and
+    /// encoding, so `a_b` and `a-b` encode identically and decode as `a-b`; `Some('_')`
+    /// does the reverse. This is lossy: the original delimiter cannot be recovered.
+    pub fn unify_delimiters(mut self, delimiter: Option<char>) -> Self {
+        self.unify_delimiters = delimiter;
+        self
+    }
+
+    /// Sets the minimum number of distinct characters, rejecting low-variety identifiers
+    /// such as `aaaaaa` or `aaaa-aaaa`.
+    ///
+    /// Letters are counted case-insensitively, and delimiters count as characters.
+    pub fn min_distinct_chars(mut self, min: Option<usize>) -> Self {
+        self.min_distinct_chars = min;
+        self
+    }
+
+    /// Sets a denylist of reserved identifiers, such as `admin` or `root`.
+    ///
+    /// Matching is ASCII case-insensitive against the whole identifier, after any
+    /// [`unify_delimiters`](Self::unify_delimiters) rewrite. The default is empty.
+    pub fn reserved(mut self, words: &'static [&'static str]) -> Self {
+        self.reserved = words;
+        self
+    }
+
+    /// Trims leading and trailing ASCII whitespace before validation and encoding, so
+    /// `" foo "` is accepted as `foo`. Interior whitespace is still rejected. Disabled by
+    /// default.
+    pub fn trim_whitespace(mut self, trim: bool) -> Self {
+        self.trim_whitespace = trim;
+        self
+    }
+
+    /// Builds a compiled [`Config`], additionally rejecting delimiter rules that
+    /// the composition makes unreachable.
+    ///
+    /// [`build`](Self::build) silently ignores such rules (e.g. allowing consecutive
+    /// hyphens under [`Composition::Alphanumeric`]); this method reports them as
+    /// [`ConfigError::InconsistentDelimiterRules`] instead. The same error is returned
+    /// when the delimiter given to [`unify_delimiters`](Self::unify_delimiters) is
+    /// forbidden by the composition.
+    pub fn try_build(self) -> Result<Config<N>, ConfigError> {
+        if let Some(rules) = self.delimiter {
+            validate_delimiter_consistency(self.composition, rules)?;
+        }
+        if let Some(delimiter) = self.unify_delimiters {
+            let (allow_hyphen, allow_underscore) = self.composition.allowed_delimiters();
+            if (delimiter == '-' && !allow_hyphen) || (delimiter == '_' && !allow_underscore) {
+                return Err(ConfigError::InconsistentDelimiterRules {
+                    composition: self.composition,
+                    delimiter,
+                });
+            }
+        }
+        self.build()
+    }
+
     /// Builds a compiled [`Config`].
     pub fn build(self) -> Result<Config<N>, ConfigError> {
         validate_length_range(self.min_length, self.max_length)?;
@@ -202,32 +474,44 @@ impl<const N: usize> ConfigBuilder<N> {
             }
         }
 
+        if let Some(rule) = self.segments {
+            if rule.delimiter != '-' && rule.delimiter != '_' {
+                return Err(ConfigError::InvalidSegmentDelimiter(rule.delimiter));
+            }
+            validate_length_range(rule.min_segments, rule.max_segments)?;
+            validate_length_range(rule.min_segment_length, rule.max_segment_length)?;
+            for &(min, max) in rule.position_lengths {
+                validate_length_range(Some(min), Some(max))?;
+            }
+        }
+
+        if let Some(delimiter) = self.unify_delimiters {
+            if delimiter != '-' && delimiter != '_' {
+                return Err(ConfigError::InvalidUnifiedDelimiter(delimiter));
+            }
+        }
+
         let delimiter_rules = self.delimiter.unwrap_or_default();
-        let (allow_hyphen, allow_underscore) = match self.composition {
-            Composition::Alphanumeric => (false, false),
-            Composition::AlphanumericHyphen => (true, false),
-            Composition::AlphanumericUnderscore => (false, true),
-            Composition::AlphanumericHyphenUnderscore => (true, true),
-        };
+        let (allow_hyphen, allow_underscore) = self.composition.allowed_delimiters();
         let needs_delimiter_pass = match self.composition {
             Composition::Alphanumeric => false,
             Composition::AlphanumericHyphen => {
                 !(delimiter_rules.allow_leading_hyphens()
                     && delimiter_rules.allow_trailing_hyphens()
-                    && delimiter_rules.allow_consecutive_hyphens())
+                    && delimiter_rules.max_hyphen_run().is_none())
             }
             Composition::AlphanumericUnderscore => {
                 !(delimiter_rules.allow_leading_underscores()
                     && delimiter_rules.allow_trailing_underscores()
-                    && delimiter_rules.allow_consecutive_underscores())
+                    && delimiter_rules.max_underscore_run().is_none())
             }
             Composition::AlphanumericHyphenUnderscore => {
                 !(delimiter_rules.allow_leading_hyphens()
                     && delimiter_rules.allow_trailing_hyphens()
                     && delimiter_rules.allow_leading_underscores()
                     && delimiter_rules.allow_trailing_underscores()
-                    && delimiter_rules.allow_consecutive_hyphens()
-                    && delimiter_rules.allow_consecutive_underscores()
+                    && delimiter_rules.max_hyphen_run().is_none()
+                    && delimiter_rules.max_underscore_run().is_none()
                     && delimiter_rules.allow_adjacent_hyphen_underscore())
             }
         };
@@ -237,6 +521,11 @@ impl<const N: usize> ConfigBuilder<N> {
             effective_max,
             composition: self.composition,
             delimiter_rules,
+            segment_rule: self.segments,
+            unified_delimiter: self.unify_delimiters,
+            min_distinct_chars: self.min_distinct_chars,
+            reserved: self.reserved,
+            trim_whitespace: self.trim_whitespace,
             allow_hyphen,
             allow_underscore,
             needs_delimiter_pass,
@@ -258,6 +547,57 @@ pub enum Composition {
     AlphanumericHyphenUnderscore,
 }
 
+impl Composition {
+    const ALL: [Self; 4] = [
+        Self::Alphanumeric,
+        Self::AlphanumericHyphen,
+        Self::AlphanumericUnderscore,
+        Self::AlphanumericHyphenUnderscore,
+    ];
+
+    /// Returns the hyphen-separated name, e.g. `"alphanumeric-hyphen"`, as accepted by
+    /// [`FromStr`](core::str::FromStr).
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Alphanumeric => "alphanumeric",
+            Self::AlphanumericHyphen => "alphanumeric-hyphen",
+            Self::AlphanumericUnderscore => "alphanumeric-underscore",
+            Self::AlphanumericHyphenUnderscore => "alphanumeric-hyphen-underscore",
+        }
+    }
+
+    /// Returns whether hyphen and underscore are allowed, in that order.
+    #[inline]
+    const fn allowed_delimiters(self) -> (bool, bool) {
+        match self {
+            Self::Alphanumeric => (false, false),
+            Self::AlphanumericHyphen => (true, false),
+            Self::AlphanumericUnderscore => (false, true),
+            Self::AlphanumericHyphenUnderscore => (true, true),
+        }
+    }
+}
+
+impl fmt::Display for Composition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for Composition {
+    type Err = ParseCompositionError;
+
+    /// Parses the hyphen-separated name returned by [`Composition::as_str`], ignoring
+    /// ASCII case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|composition| composition.as_str().eq_ignore_ascii_case(s))
+            .ok_or(ParseCompositionError)
+    }
+}
+
 /// Rules for allowed delimiters.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
 pub struct DelimiterRules {
@@ -268,6 +608,8 @@ pub struct DelimiterRules {
     allow_consecutive_hyphens: bool,
     allow_consecutive_underscores: bool,
     allow_adjacent_hyphen_underscore: bool,
+    max_consecutive_hyphens: Option<u8>,
+    max_consecutive_underscores: Option<u8>,
 }
 
 impl DelimiterRules {
@@ -289,6 +631,8 @@ impl DelimiterRules {
             allow_consecutive_hyphens,
             allow_consecutive_underscores,
             allow_adjacent_hyphen_underscore,
+            max_consecutive_hyphens: None,
+            max_consecutive_underscores: None,
         }
     }
 
@@ -302,6 +646,8 @@ impl DelimiterRules {
             allow_consecutive_hyphens: true,
             allow_consecutive_underscores: true,
             allow_adjacent_hyphen_underscore: true,
+            max_consecutive_hyphens: None,
+            max_consecutive_underscores: None,
         }
     }
 
@@ -344,10 +690,50 @@ impl DelimiterRules {
     pub fn allow_adjacent_hyphen_underscore(&self) -> bool {
         self.allow_adjacent_hyphen_underscore
     }
+
+    /// Maximum number of consecutive hyphens, overriding
+    /// [`allow_consecutive_hyphens`](Self::allow_consecutive_hyphens) when set.
+    pub fn max_consecutive_hyphens(&self) -> Option<u8> {
+        self.max_consecutive_hyphens
+    }
+
+    /// Maximum number of consecutive underscores, overriding
+    /// [`allow_consecutive_underscores`](Self::allow_consecutive_underscores) when set.
+    pub fn max_consecutive_underscores(&self) -> Option<u8> {
+        self.max_consecutive_underscores
+    }
+
+    /// Returns the longest allowed run of hyphens, or `None` if runs are unlimited.
+    ///
+    /// This resolves [`max_consecutive_hyphens`](Self::max_consecutive_hyphens) and
+    /// [`allow_consecutive_hyphens`](Self::allow_consecutive_hyphens): a maximum of `0`
+    /// or `1`, or disallowed consecutive hyphens without a maximum, gives `Some(1)`.
+    pub fn max_hyphen_run(&self) -> Option<usize> {
+        max_run(self.max_consecutive_hyphens, self.allow_consecutive_hyphens)
+    }
+
+    /// Returns the longest allowed run of underscores, or `None` if runs are unlimited.
+    ///
+    /// The underscore counterpart of [`max_hyphen_run`](Self::max_hyphen_run).
+    pub fn max_underscore_run(&self) -> Option<usize> {
+        max_run(
+            self.max_consecutive_underscores,
+            self.allow_consecutive_underscores,
+        )
+    }
+}
+
+#[inline]
+fn max_run(max_consecutive: Option<u8>, allow_consecutive: bool) -> Option<usize> {
+    match max_consecutive {
+        Some(max) => Some(usize::from(max.max(1))),
+        None if allow_consecutive => None,
+        None => Some(1),
+    }
 }
 
 /// Builder for [`DelimiterRules`].
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct DelimiterRulesBuilder {
     allow_leading_hyphens: Option<bool>,
     allow_trailing_hyphens: Option<bool>,
@@ -356,6 +742,8 @@ pub struct DelimiterRulesBuilder {
     allow_consecutive_hyphens: Option<bool>,
     allow_consecutive_underscores: Option<bool>,
     allow_adjacent_hyphen_underscore: Option<bool>,
+    max_consecutive_hyphens: Option<u8>,
+    max_consecutive_underscores: Option<u8>,
 }
 
 impl DelimiterRulesBuilder {
@@ -406,6 +794,27 @@ impl DelimiterRulesBuilder {
         self
     }
 
+    /// Sets the maximum number of consecutive hyphens, e.g. `Some(2)` to accept `a--b` but
+    /// reject `a---b`.
+    ///
+    /// When set, this takes precedence over
+    /// [`allow_consecutive_hyphens`](Self::allow_consecutive_hyphens); `None` keeps the
+    /// boolean rule.
+    pub fn max_consecutive_hyphens(mut self, max: Option<u8>) -> Self {
+        self.max_consecutive_hyphens = max;
+        self
+    }
+
+    /// Sets the maximum number of consecutive underscores.
+    ///
+    /// When set, this takes precedence over
+    /// [`allow_consecutive_underscores`](Self::allow_consecutive_underscores); `None` keeps
+    /// the boolean rule.
+    pub fn max_consecutive_underscores(mut self, max: Option<u8>) -> Self {
+        self.max_consecutive_underscores = max;
+        self
+    }
+
     /// Builds the [`DelimiterRules`] object.
     ///
     /// Missing rules default to false.
@@ -420,6 +829,134 @@ impl DelimiterRulesBuilder {
             allow_adjacent_hyphen_underscore: self
                 .allow_adjacent_hyphen_underscore
                 .unwrap_or(false),
+            max_consecutive_hyphens: self.max_consecutive_hyphens,
+            max_consecutive_underscores: self.max_consecutive_underscores,
+        }
+    }
+}
+
+/// Structural rule for delimiter-separated segments, e.g. `<alnum>-<alnum>-<alnum>`.
+///
+/// The input is split on [`delimiter`](Self::delimiter); the number of resulting
+/// segments and the length of each segment must fall within the configured ranges.
+/// [`position_lengths`](Self::position_lengths) can further constrain the segment at a
+/// given position, e.g. a region code of exactly three characters.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct SegmentRule {
+    delimiter: char,
+    min_segments: Option<usize>,
+    max_segments: Option<usize>,
+    min_segment_length: Option<usize>,
+    max_segment_length: Option<usize>,
+    position_lengths: &'static [(usize, usize)],
+}
+
+impl SegmentRule {
+    /// Creates a new builder for a segment rule.
+    pub fn builder() -> SegmentRuleBuilder {
+        SegmentRuleBuilder::new()
+    }
+
+    /// Delimiter separating segments.
+    pub fn delimiter(&self) -> char {
+        self.delimiter
+    }
+
+    /// Minimum number of segments.
+    pub fn min_segments(&self) -> Option<usize> {
+        self.min_segments
+    }
+
+    /// Maximum number of segments.
+    pub fn max_segments(&self) -> Option<usize> {
+        self.max_segments
+    }
+
+    /// Minimum length of each segment.
+    pub fn min_segment_length(&self) -> Option<usize> {
+        self.min_segment_length
+    }
+
+    /// Maximum length of each segment.
+    pub fn max_segment_length(&self) -> Option<usize> {
+        self.max_segment_length
+    }
+
+    /// Inclusive `(min, max)` length of the segment at each position, starting from the
+    /// first segment; empty if no position is constrained.
+    pub fn position_lengths(&self) -> &'static [(usize, usize)] {
+        self.position_lengths
+    }
+}
+
+/// Builder for [`SegmentRule`].
+#[derive(Clone, Default)]
+pub struct SegmentRuleBuilder {
+    delimiter: Option<char>,
+    min_segments: Option<usize>,
+    max_segments: Option<usize>,
+    min_segment_length: Option<usize>,
+    max_segment_length: Option<usize>,
+    position_lengths: &'static [(usize, usize)],
+}
+
+impl SegmentRuleBuilder {
+    /// Creates a new builder for a segment rule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the delimiter separating segments.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Sets the minimum number of segments.
+    pub fn min_segments(mut self, min: Option<usize>) -> Self {
+        self.min_segments = min;
+        self
+    }
+
+    /// Sets the maximum number of segments.
+    pub fn max_segments(mut self, max: Option<usize>) -> Self {
+        self.max_segments = max;
+        self
+    }
+
+    /// Sets the minimum length of each segment.
+    pub fn min_segment_length(mut self, min: Option<usize>) -> Self {
+        self.min_segment_length = min;
+        self
+    }
+
+    /// Sets the maximum length of each segment.
+    pub fn max_segment_length(mut self, max: Option<usize>) -> Self {
+        self.max_segment_length = max;
+        self
+    }
+
+    /// Sets inclusive `(min, max)` lengths for the segments at the first positions.
+    ///
+    /// The `i`-th entry applies to the `i`-th segment, in addition to the uniform segment
+    /// length bounds; segments past the end of the slice are only checked against those.
+    /// The default is empty.
+    pub fn position_lengths(mut self, lengths: &'static [(usize, usize)]) -> Self {
+        self.position_lengths = lengths;
+        self
+    }
+
+    /// Builds the [`SegmentRule`] object.
+    ///
+    /// The delimiter defaults to a hyphen; missing bounds are unrestricted.
+    pub fn build(self) -> SegmentRule {
+        SegmentRule {
+            delimiter: self.delimiter.unwrap_or('-'),
+            min_segments: self.min_segments,
+            max_segments: self.max_segments,
+            min_segment_length: self.min_segment_length,
+            max_segment_length: self.max_segment_length,
+            position_lengths: self.position_lengths,
         }
     }
 }
@@ -473,6 +1010,7 @@ mod tests {
             Composition::AlphanumericHyphenUnderscore
         );
         assert!(config.delimiter_rules().allow_adjacent_hyphen_underscore());
+        assert_eq!(config.delimiter_rules_ref(), &config.delimiter_rules());
     }
 
     #[test]
@@ -528,6 +1066,56 @@ mod tests {
         assert!(rules.allow_adjacent_hyphen_underscore());
     }
 
+    #[test]
+    fn test_delimiter_rules_max_consecutive() {
+        let rules = DelimiterRules::default();
+        assert_eq!(rules.max_consecutive_hyphens(), None);
+        assert_eq!(rules.max_hyphen_run(), Some(1));
+        assert_eq!(DelimiterRules::all_allowed().max_underscore_run(), None);
+
+        let rules = DelimiterRules::builder()
+            .max_consecutive_hyphens(Some(2))
+            .allow_consecutive_underscores(true)
+            .max_consecutive_underscores(Some(0))
+            .build();
+        assert_eq!(rules.max_consecutive_hyphens(), Some(2));
+        assert_eq!(rules.max_hyphen_run(), Some(2));
+        assert_eq!(rules.max_consecutive_underscores(), Some(0));
+        assert_eq!(rules.max_underscore_run(), Some(1));
+
+        // A run limit makes the config check delimiters even when everything else is allowed.
+        let config = Config::<16>::builder()
+            .composition(Composition::AlphanumericHyphen)
+            .delimiter(Some(
+                DelimiterRules::builder()
+                    .allow_leading_hyphens(true)
+                    .allow_trailing_hyphens(true)
+                    .allow_consecutive_hyphens(true)
+                    .max_consecutive_hyphens(Some(3))
+                    .build(),
+            ))
+            .build()
+            .unwrap();
+        assert!(config.needs_delimiter_pass());
+
+        let err = Config::<16>::builder()
+            .composition(Composition::Alphanumeric)
+            .delimiter(Some(
+                DelimiterRules::builder()
+                    .max_consecutive_underscores(Some(2))
+                    .build(),
+            ))
+            .try_build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ConfigError::InconsistentDelimiterRules {
+                composition: Composition::Alphanumeric,
+                delimiter: '_',
+            }
+        );
+    }
+
     #[test]
     fn test_invalid_length_config_builder() {
         let err = Config::<16>::builder()
@@ -536,6 +1124,10 @@ mod tests {
             .build()
             .unwrap_err();
         assert_eq!(err, ConfigError::InvalidLengthRange { min: 10, max: 5 });
+        assert_eq!(
+            err.to_string(),
+            "Minimum length 10 cannot be greater than maximum length 5"
+        );
     }
 
     #[test]
@@ -548,5 +1140,301 @@ mod tests {
             err,
             ConfigError::InvalidCompiledLengthRange { min: 20, max: 10 }
         );
+        assert_eq!(
+            err.to_string(),
+            "Minimum length 20 cannot be greater than compiled maximum length 10"
+        );
+    }
+
+    #[test]
+    fn test_try_build_inconsistent_hyphen_rules() {
+        let err = Config::<16>::builder()
+            .composition(Composition::Alphanumeric)
+            .delimiter(Some(
+                DelimiterRules::builder()
+                    .allow_consecutive_hyphens(true)
+                    .build(),
+            ))
+            .try_build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ConfigError::InconsistentDelimiterRules {
+                composition: Composition::Alphanumeric,
+                delimiter: '-',
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_build_inconsistent_underscore_rules() {
+        let err = Config::<16>::builder()
+            .composition(Composition::AlphanumericHyphen)
+            .delimiter(Some(DelimiterRules::all_allowed()))
+            .try_build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ConfigError::InconsistentDelimiterRules {
+                composition: Composition::AlphanumericHyphen,
+                delimiter: '_',
+            }
+        );
+
+        // `build` keeps accepting the same combination.
+        assert!(
+            Config::<16>::builder()
+                .composition(Composition::AlphanumericHyphen)
+                .delimiter(Some(DelimiterRules::all_allowed()))
+                .build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_try_build_consistent() {
+        let config = Config::<16>::builder()
+            .composition(Composition::AlphanumericUnderscore)
+            .delimiter(Some(
+                DelimiterRules::builder()
+                    .allow_leading_underscores(true)
+                    .build(),
+            ))
+            .try_build()
+            .unwrap();
+        assert!(config.delimiter_rules().allow_leading_underscores());
+        assert!(Config::<16>::builder().try_build().is_ok());
+    }
+
+    #[test]
+    fn test_config_builder_length_helpers() {
+        let config = Config::<16>::builder()
+            .with_min_length(4)
+            .with_max_length(12)
+            .build()
+            .unwrap();
+        assert_eq!(config.min_length(), Some(4));
+        assert_eq!(config.effective_max(), 12);
+
+        let config = Config::<16>::builder()
+            .with_min_length(4)
+            .without_min_length()
+            .with_max_length(12)
+            .without_max_length()
+            .build()
+            .unwrap();
+        assert_eq!(config.min_length(), None);
+        assert_eq!(config.effective_max(), 21);
+    }
+
+    #[test]
+    fn test_config_length_helpers() {
+        let config = Config::<16>::default();
+
+        let config = config.with_min_length(5).unwrap();
+        assert_eq!(config.min_length(), Some(5));
+        assert_eq!(config.effective_max(), 21);
+
+        let config = config.with_max_length(10).unwrap();
+        assert_eq!(config.min_length(), Some(5));
+        assert_eq!(config.effective_max(), 10);
+        assert_eq!(config.composition(), Composition::AlphanumericHyphen);
+
+        let config = config.without_max_length();
+        assert_eq!(config.effective_max(), 21);
+
+        let config = config.without_min_length();
+        assert_eq!(config.min_length(), None);
+        assert_eq!(
+            config,
+            Config::<16>::builder().min_length(None).build().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_config_length_bounds() {
+        let config = Config::<16>::default();
+        assert_eq!(config.min_length_or_zero(), 3);
+        assert_eq!(config.without_min_length().min_length_or_zero(), 0);
+
+        assert_eq!(config.effective_max_for_bytes::<16>(), 21);
+        assert_eq!(config.effective_max_for_bytes::<32>(), 21);
+        assert_eq!(config.effective_max_for_bytes::<8>(), 10);
+
+        let config = config.with_max_length(8).unwrap();
+        assert_eq!(config.effective_max_for_bytes::<8>(), 8);
+        assert_eq!(config.effective_max_for_bytes::<4>(), 5);
+    }
+
+    #[test]
+    fn test_config_length_helpers_invalid() {
+        let config = Config::<16>::builder().max_length(Some(8)).build().unwrap();
+        assert_eq!(
+            config.with_min_length(9).unwrap_err(),
+            ConfigError::InvalidLengthRange { min: 9, max: 8 }
+        );
+        assert_eq!(
+            Config::<16>::default().with_max_length(2).unwrap_err(),
+            ConfigError::InvalidLengthRange { min: 3, max: 2 }
+        );
+    }
+
+    #[test]
+    fn test_config_segments() {
+        let rule = SegmentRule::builder()
+            .min_segments(Some(3))
+            .max_segments(Some(3))
+            .max_segment_length(Some(6))
+            .build();
+        assert_eq!(rule.delimiter(), '-');
+
+        let config = Config::<16>::builder()
+            .segments(Some(rule))
+            .build()
+            .unwrap();
+        assert_eq!(config.segment_rule(), Some(rule));
+        assert_eq!(Config::<16>::default().segment_rule(), None);
+        assert_eq!(
+            config.with_min_length(4).unwrap().segment_rule(),
+            Some(rule)
+        );
+    }
+
+    #[test]
+    fn test_config_segments_invalid() {
+        let err = Config::<16>::builder()
+            .segments(Some(SegmentRule::builder().delimiter('.').build()))
+            .build()
+            .unwrap_err();
+        assert_eq!(err, ConfigError::InvalidSegmentDelimiter('.'));
+
+        let err = Config::<16>::builder()
+            .segments(Some(
+                SegmentRule::builder()
+                    .min_segments(Some(4))
+                    .max_segments(Some(2))
+                    .build(),
+            ))
+            .build()
+            .unwrap_err();
+        assert_eq!(err, ConfigError::InvalidLengthRange { min: 4, max: 2 });
+
+        let err = Config::<16>::builder()
+            .segments(Some(
+                SegmentRule::builder()
+                    .position_lengths(&[(3, 3), (5, 1)])
+                    .build(),
+            ))
+            .build()
+            .unwrap_err();
+        assert_eq!(err, ConfigError::InvalidLengthRange { min: 5, max: 1 });
+    }
+
+    #[test]
+    fn test_config_unify_delimiters() {
+        let config = Config::<16>::builder()
+            .unify_delimiters(Some('-'))
+            .build()
+            .unwrap();
+        assert_eq!(config.unified_delimiter(), Some('-'));
+        assert_eq!(Config::<16>::default().unified_delimiter(), None);
+        assert_eq!(
+            config.with_min_length(4).unwrap().unified_delimiter(),
+            Some('-')
+        );
+
+        let err = Config::<16>::builder()
+            .unify_delimiters(Some('.'))
+            .build()
+            .unwrap_err();
+        assert_eq!(err, ConfigError::InvalidUnifiedDelimiter('.'));
+
+        let err = Config::<16>::builder()
+            .unify_delimiters(Some('_'))
+            .try_build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ConfigError::InconsistentDelimiterRules {
+                composition: Composition::AlphanumericHyphen,
+                delimiter: '_',
+            }
+        );
+    }
+
+    #[test]
+    fn test_config_builder_clone() {
+        let base = Config::<16>::builder().min_length(Some(3));
+        let strict = base.clone().max_length(Some(8)).build().unwrap();
+        let lenient = base.max_length(Some(16)).build().unwrap();
+        assert_eq!(strict.min_length(), Some(3));
+        assert_eq!(lenient.min_length(), Some(3));
+        assert_eq!(strict.effective_max(), 8);
+        assert_eq!(lenient.effective_max(), 16);
+
+        let base = DelimiterRules::builder().allow_leading_hyphens(true);
+        let rules = base.clone().allow_trailing_hyphens(true).build();
+        assert!(rules.allow_trailing_hyphens());
+        assert!(!base.build().allow_trailing_hyphens());
+    }
+
+    #[test]
+    fn test_config_min_distinct_chars() {
+        assert_eq!(Config::<16>::default().min_distinct_chars(), None);
+
+        let config = Config::<16>::builder()
+            .min_distinct_chars(Some(2))
+            .build()
+            .unwrap();
+        assert_eq!(config.min_distinct_chars(), Some(2));
+        assert_eq!(
+            config.with_max_length(8).unwrap().min_distinct_chars(),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_config_reserved() {
+        assert!(Config::<16>::default().reserved().is_empty());
+
+        let config = Config::<16>::builder()
+            .reserved(&["admin", "root"])
+            .build()
+            .unwrap();
+        assert_eq!(config.reserved(), &["admin", "root"]);
+        assert_eq!(
+            config.with_min_length(4).unwrap().reserved(),
+            &["admin", "root"]
+        );
+    }
+
+    #[test]
+    fn test_config_trim_whitespace() {
+        assert!(!Config::<16>::default().trim_whitespace());
+
+        let config = Config::<16>::builder()
+            .trim_whitespace(true)
+            .build()
+            .unwrap();
+        assert!(config.trim_whitespace());
+        assert!(config.with_min_length(4).unwrap().trim_whitespace());
+    }
+
+    #[test]
+    fn test_composition_str_round_trip() {
+        for composition in Composition::ALL {
+            assert_eq!(composition.as_str().parse(), Ok(composition));
+            assert_eq!(composition.to_string(), composition.as_str());
+        }
+        assert_eq!(
+            "Alphanumeric-Hyphen".parse(),
+            Ok(Composition::AlphanumericHyphen)
+        );
+        assert_eq!("ALPHANUMERIC".parse(), Ok(Composition::Alphanumeric));
+        assert_eq!(
+            "alphanumeric_hyphen".parse::<Composition>(),
+            Err(ParseCompositionError)
+        );
+        assert_eq!("".parse::<Composition>(), Err(ParseCompositionError));
     }
 }
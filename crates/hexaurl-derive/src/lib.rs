@@ -0,0 +1,148 @@
+#![doc = include_str!("../README.md")]
+#![warn(missing_docs)]
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitStr, parse_macro_input};
+
+/// Derives constructors and forwarding impls for a newtype wrapping a HexaURL type.
+///
+/// The input must be a non-generic tuple struct with exactly one field whose type is a
+/// `HexaUrlCore` alias such as `hexaurl::HexaUrl` or `hexaurl::struct_api::HexaUrl32`.
+///
+/// The following items are generated, each forwarding to the wrapped value:
+///
+/// - `new(&str) -> Result<Self, hexaurl::Error>`
+/// - `as_bytes(&self) -> &[u8]`
+/// - `TryFrom<&str>`
+/// - `Display`
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::HexaUrl;
+/// use hexaurl_derive::HexaUrlNewtype;
+///
+/// #[derive(HexaUrlNewtype)]
+/// struct UserId(HexaUrl);
+///
+/// let id = UserId::new("Some-User").unwrap();
+/// assert_eq!(id.to_string(), "some-user");
+/// ```
+#[proc_macro_derive(HexaUrlNewtype)]
+pub fn derive_hexaurl_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "HexaUrlNewtype cannot be derived for generic types",
+        ));
+    }
+
+    let inner = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input,
+                    "HexaUrlNewtype requires a tuple struct with exactly one field",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "HexaUrlNewtype can only be derived for structs",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #name {
+            /// Creates a new value from the input string with the default configuration.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the wrapped type rejects the input.
+            #[inline]
+            pub fn new(input: &str) -> ::core::result::Result<Self, ::hexaurl::Error> {
+                <#inner>::new(input).map(Self)
+            }
+
+            /// Returns the encoded bytes of the wrapped value.
+            #[inline]
+            pub fn as_bytes(&self) -> &[u8] {
+                self.0.as_bytes()
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for #name {
+            type Error = ::hexaurl::Error;
+
+            #[inline]
+            fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                <#inner as ::core::convert::TryFrom<&str>>::try_from(value).map(Self)
+            }
+        }
+
+        impl ::core::fmt::Display for #name {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+    })
+}
+
+/// Encodes a string literal into a `hexaurl::HexaUrl` at compile time.
+///
+/// The literal is validated with the default configuration, as by `HexaUrl::new`, and the
+/// expansion encodes it with `HexaUrl::new_const` in a `const` item, so it can also
+/// initialize a `const` and needs no `unsafe`. An invalid literal fails the build with an
+/// error pointing at it.
+///
+/// The macro always produces the 16-byte `hexaurl::HexaUrl`, so literals are limited to 21
+/// characters. For other sizes, call `HexaUrlCore::new_const` on the wanted alias, e.g.
+/// `HexaUrl32::new_const("...")`; it checks the length and characters but not the
+/// delimiter rules.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::HexaUrl;
+/// use hexaurl_derive::hexaurl;
+///
+/// const DASHBOARD: HexaUrl = hexaurl!("dashboard");
+/// assert_eq!(DASHBOARD, HexaUrl::new("dashboard").unwrap());
+/// ```
+#[proc_macro]
+pub fn hexaurl(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    expand_literal(&lit)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Byte size of `hexaurl::HexaUrl`.
+const HEXAURL_BYTES: usize = 16;
+
+fn expand_literal(lit: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
+    let value = lit.value();
+    hexaurl_validate::validate::<HEXAURL_BYTES>(&value)
+        .map_err(|err| syn::Error::new(lit.span(), format!("invalid HexaURL literal: {err}")))?;
+
+    Ok(quote! {
+        {
+            const VALUE: ::hexaurl::HexaUrl = ::hexaurl::HexaUrl::new_const(#lit);
+            VALUE
+        }
+    })
+}
@@ -0,0 +1,23 @@
+use hexaurl::{HexaUrl, struct_api::HexaUrl8};
+use hexaurl_derive::hexaurl;
+
+const DASHBOARD: HexaUrl = hexaurl!("dashboard");
+
+#[test]
+fn test_literal_matches_new() {
+    assert_eq!(DASHBOARD, HexaUrl::new("dashboard").unwrap());
+    assert_eq!(hexaurl!("Mixed-Case"), HexaUrl::new("Mixed-Case").unwrap());
+    assert_eq!(hexaurl!("user-42"), HexaUrl::new("user-42").unwrap());
+    assert_eq!(
+        hexaurl!("ultimate-august-champ"),
+        HexaUrl::new("ultimate-august-champ").unwrap()
+    );
+    assert_eq!(hexaurl!("abcdefgh"), HexaUrl8::new("abcdefgh").unwrap());
+}
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/literal_valid.rs");
+    t.compile_fail("tests/ui/literal_invalid.rs");
+}
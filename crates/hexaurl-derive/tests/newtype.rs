@@ -0,0 +1,39 @@
+use hexaurl::{Error, HexaUrl, struct_api::HexaUrl8};
+use hexaurl_derive::HexaUrlNewtype;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, HexaUrlNewtype)]
+struct UserId(HexaUrl);
+
+#[derive(Debug, HexaUrlNewtype)]
+struct ShortId(HexaUrl8);
+
+#[test]
+fn test_new() {
+    let id = UserId::new("Some-User").unwrap();
+    assert_eq!(id, UserId(HexaUrl::new("some-user").unwrap()));
+    assert_eq!(UserId::new("ab"), Err(Error::StringTooShort(3)));
+    assert!(ShortId::new("much-too-long").is_err());
+}
+
+#[test]
+fn test_try_from() {
+    let id = UserId::try_from("some-user").unwrap();
+    assert_eq!(id.0, HexaUrl::try_from("some-user").unwrap());
+    assert!(UserId::try_from("invalid!").is_err());
+}
+
+#[test]
+fn test_display() {
+    let id = UserId::new("Some-User").unwrap();
+    assert_eq!(id.to_string(), "some-user");
+    assert_eq!(format!("{id:>10}"), " some-user");
+}
+
+#[test]
+fn test_as_bytes() {
+    let id = UserId::new("some-user").unwrap();
+    assert_eq!(id.as_bytes(), id.0.as_bytes());
+
+    let short = ShortId::new("abc").unwrap();
+    assert_eq!(short.as_bytes().len(), 8);
+}
@@ -0,0 +1,6 @@
+use hexaurl::HexaUrl;
+use hexaurl_derive::hexaurl;
+
+const DASHBOARD: HexaUrl = hexaurl!("dash board");
+
+fn main() {}
@@ -0,0 +1,10 @@
+#![forbid(unsafe_code)]
+
+use hexaurl::HexaUrl;
+use hexaurl_derive::hexaurl;
+
+const DASHBOARD: HexaUrl = hexaurl!("dashboard");
+
+fn main() {
+    assert_eq!(DASHBOARD.to_string(), "dashboard");
+}
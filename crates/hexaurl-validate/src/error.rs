@@ -1,5 +1,9 @@
 /// Errors that can occur when working with HexaURL
+///
+/// New variants may be added in minor releases; match on [`Error::kind`] for a stable
+/// category.
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     // Common errors
     /// The input string length is too long
@@ -22,6 +26,10 @@ pub enum Error {
     #[error("Invalid character in this type of HexaURL")]
     InvalidCharacter,
 
+    /// The input includes an ASCII control character, such as a tab or newline
+    #[error("Control characters are not allowed in this type of HexaURL")]
+    ControlCharacter,
+
     /// The input includes bytes invalid for this type of HexaURL encoding
     #[error("Invalid byte in this type of HexaURL")]
     InvalidByte,
@@ -54,4 +62,103 @@ pub enum Error {
     /// The input includes adjacent hyphens and underscores (not allowed by configuration)
     #[error("This type of HexaURL cannot include adjacent hyphens and underscores")]
     AdjacentHyphenUnderscore,
+
+    /// The input has a number of delimiter-separated segments outside the configured range
+    #[error("Segment count {0} is outside the range allowed by this type of HexaURL")]
+    SegmentCount(usize),
+
+    /// The input has a delimiter-separated segment whose length is outside the configured range
+    #[error("Segment length {0} is outside the range allowed by this type of HexaURL")]
+    SegmentLength(usize),
+
+    /// The input has a segment whose length is outside the range configured for its position
+    #[error("Segment {0} has a length outside the range allowed at its position")]
+    SegmentPositionLength(usize),
+
+    /// The input has fewer distinct characters than the configured minimum
+    #[error("Only {0} distinct characters, fewer than this type of HexaURL requires")]
+    InsufficientVariety(usize),
+
+    /// The input matches an identifier reserved by configuration
+    #[error("This identifier is reserved")]
+    Reserved,
+}
+
+/// A coarse category of [`Error`], stable as new specific variants are added.
+///
+/// Match on [`Error::kind`] instead of individual variants when only the category matters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input is too long or too short
+    LengthError,
+
+    /// The input contains characters or bytes that are not allowed, or its content is rejected
+    /// as a whole (too few distinct characters, a reserved identifier)
+    CharacterError,
+
+    /// The input places hyphens or underscores, or the segments between them, in a way the
+    /// configuration does not allow
+    DelimiterError,
+
+    /// The configuration itself is invalid
+    ConfigError,
+
+    /// The input length does not match the length required by the encoding
+    LengthMismatch,
+}
+
+impl Error {
+    /// Returns the error for a byte that is not allowed in the input:
+    /// [`ControlCharacter`](Self::ControlCharacter) for ASCII control characters
+    /// (`0x00`-`0x1F` and `0x7F`), [`InvalidCharacter`](Self::InvalidCharacter) otherwise.
+    #[inline]
+    pub const fn invalid_character(byte: u8) -> Self {
+        if byte.is_ascii_control() {
+            Error::ControlCharacter
+        } else {
+            Error::InvalidCharacter
+        }
+    }
+
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::StringTooLong(_)
+            | Error::StringTooShort(_)
+            | Error::BytesTooLong(_)
+            | Error::BytesTooShort(_) => ErrorKind::LengthError,
+            Error::InvalidCharacter
+            | Error::ControlCharacter
+            | Error::InvalidByte
+            | Error::InsufficientVariety(_)
+            | Error::Reserved => ErrorKind::CharacterError,
+            Error::LeadingTrailingHyphen
+            | Error::LeadingTrailingUnderscore
+            | Error::ConsecutiveHyphens
+            | Error::ConsecutiveUnderscores
+            | Error::AdjacentHyphenUnderscore
+            | Error::SegmentCount(_)
+            | Error::SegmentLength(_)
+            | Error::SegmentPositionLength(_) => ErrorKind::DelimiterError,
+            Error::InvalidConfig(_, _) => ErrorKind::ConfigError,
+            Error::InvalidLength => ErrorKind::LengthMismatch,
+        }
+    }
+
+    /// Converts the error into an [`std::io::Error`] of kind
+    /// [`InvalidData`](std::io::ErrorKind::InvalidData).
+    ///
+    /// The error is kept as the payload, so the I/O error displays the same message and
+    /// the original value can be recovered with [`std::io::Error::get_ref`] or
+    /// [`std::io::Error::into_inner`].
+    pub fn into_io_error(self) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, self)
+    }
+}
+
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> Self {
+        err.into_io_error()
+    }
 }
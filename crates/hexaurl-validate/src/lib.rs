@@ -2,21 +2,25 @@
 #![warn(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-use config::Composition;
 pub use config::Config;
+use config::{Composition, SegmentRule};
 pub use hexaurl_config as config;
-use std::convert::TryInto;
+use std::{borrow::Cow, convert::TryInto};
 
 mod error;
+mod report;
 #[cfg(not(feature = "char"))]
 mod validate_char;
 #[cfg(feature = "char")]
 #[cfg_attr(docsrs, doc(cfg(feature = "char")))]
 pub mod validate_char;
 
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod validate_simd;
 mod validate_swar;
 
-pub use error::Error;
+pub use error::{Error, ErrorKind};
+pub use report::{ValidationReport, analyze};
 
 /// Compiles a runtime config for repeated validation calls.
 #[inline]
@@ -24,35 +28,56 @@ pub fn compile_config<const N: usize>(config: Config<N>) -> Result<Config<N>, Er
     Ok(config)
 }
 
-/// Calculates the length of the decoded string based on the number of input bytes.
+/// Returns the maximum string length that fits in `byte_capacity` bytes, i.e. `S` for a
+/// `HexaUrlCore<N, S>` with `N = byte_capacity`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl_validate::max_str_len;
+///
+/// assert_eq!(max_str_len(16), 21);
+/// assert_eq!(max_str_len(8), 10);
+/// ```
 #[inline(always)]
-const fn calc_str_len(n: usize) -> usize {
-    n * 4 / 3
+pub const fn max_str_len(byte_capacity: usize) -> usize {
+    byte_capacity * 4 / 3
 }
 
+/// Returns the smallest byte capacity that fits strings of up to `max_str_len` characters,
+/// the inverse of [`max_str_len`] rounded up.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl_validate::{max_str_len, min_byte_capacity};
+///
+/// assert_eq!(min_byte_capacity(21), 16);
+/// assert_eq!(min_byte_capacity(22), 17);
+/// assert!(max_str_len(min_byte_capacity(30)) >= 30);
+/// ```
 #[inline(always)]
-fn has_consecutive_delimiter(bytes: &[u8], needle: u8) -> bool {
-    const BYTE_HIGH_BITS: u64 = 0x8080808080808080;
-    const BYTE_ONES: u64 = 0x0101010101010101;
-    const FIRST_BYTE_HIGH_BIT: u64 = 0x0000000000000080;
-    const LAST_BYTE_HIGH_BIT: u64 = 0x8000000000000000;
+pub const fn min_byte_capacity(max_str_len: usize) -> usize {
+    (max_str_len * 3).div_ceil(4)
+}
 
+/// Returns whether `bytes` contains two consecutive `needle` bytes.
+///
+/// Each 8-byte chunk is checked by `has_pair_in_chunk`, so only pairs spanning two chunks,
+/// and the remainder, are checked per byte.
+#[inline(always)]
+fn has_consecutive_delimiter(bytes: &[u8], needle: u8, has_pair_in_chunk: fn(u64) -> bool) -> bool {
     let mut prev_last_match = false;
-    let repeated = u64::from(needle) * BYTE_ONES;
 
     let mut chunks = bytes.chunks_exact(8);
     for chunk in chunks.by_ref() {
-        let value = u64::from_le_bytes(chunk.try_into().unwrap());
-        let x = value ^ repeated;
-        let match_mask = (x.wrapping_sub(BYTE_ONES)) & !x & BYTE_HIGH_BITS;
-
-        if (match_mask & (match_mask >> 8)) != 0 {
+        if has_pair_in_chunk(u64::from_le_bytes(chunk.try_into().unwrap())) {
             return true;
         }
-        if prev_last_match && (match_mask & FIRST_BYTE_HIGH_BIT) != 0 {
+        if prev_last_match && chunk[0] == needle {
             return true;
         }
-        prev_last_match = (match_mask & LAST_BYTE_HIGH_BIT) != 0;
+        prev_last_match = chunk[7] == needle;
     }
 
     for &b in chunks.remainder() {
@@ -69,14 +94,47 @@ fn has_consecutive_delimiter(bytes: &[u8], needle: u8) -> bool {
     false
 }
 
+/// Returns whether `bytes` contains a run of `needle` longer than `max_run`.
+///
+/// A limit of one uses the SWAR check above; `None` means runs are unlimited.
+#[inline(always)]
+fn exceeds_delimiter_run(bytes: &[u8], needle: u8, max_run: Option<usize>) -> bool {
+    match max_run {
+        None => false,
+        Some(1) => match needle {
+            b'-' => {
+                has_consecutive_delimiter(bytes, needle, validate_swar::has_consecutive_hyphens)
+            }
+            _ => {
+                has_consecutive_delimiter(bytes, needle, validate_swar::has_consecutive_underscores)
+            }
+        },
+        Some(max_run) => {
+            let mut run = 0usize;
+            for &b in bytes {
+                if b == needle {
+                    run += 1;
+                    if run > max_run {
+                        return true;
+                    }
+                } else {
+                    run = 0;
+                }
+            }
+            false
+        }
+    }
+}
+
 #[inline(always)]
 fn first_mixed_delimiter_violation(
     bytes: &[u8],
-    check_consecutive_hyphen: bool,
-    check_consecutive_underscore: bool,
+    max_hyphen_run: Option<usize>,
+    max_underscore_run: Option<usize>,
     check_adjacent_mixed: bool,
 ) -> Option<Error> {
     let mut prev = 0u8;
+    let mut run = 0usize;
     let mut i = 0usize;
     let len = bytes.len();
     let ptr = bytes.as_ptr();
@@ -85,17 +143,19 @@ fn first_mixed_delimiter_violation(
         // SAFETY: `i < len` guarantees this pointer read is in-bounds.
         let b = unsafe { *ptr.add(i) };
         if b == b'-' || b == b'_' {
-            if prev != 0 {
-                if prev == b {
-                    if b == b'-' && check_consecutive_hyphen {
-                        return Some(Error::ConsecutiveHyphens);
-                    }
-                    if b == b'_' && check_consecutive_underscore {
-                        return Some(Error::ConsecutiveUnderscores);
-                    }
-                } else if check_adjacent_mixed {
+            if prev == b {
+                run += 1;
+                if b == b'-' && max_hyphen_run.is_some_and(|max| run > max) {
+                    return Some(Error::ConsecutiveHyphens);
+                }
+                if b == b'_' && max_underscore_run.is_some_and(|max| run > max) {
+                    return Some(Error::ConsecutiveUnderscores);
+                }
+            } else {
+                if prev != 0 && check_adjacent_mixed {
                     return Some(Error::AdjacentHyphenUnderscore);
                 }
+                run = 1;
             }
             prev = b;
         } else {
@@ -129,6 +189,180 @@ pub fn validate_with_config<const N: usize>(input: &str, config: &Config<N>) ->
 pub fn validate_with_compiled_config<const N: usize>(
     input: &str,
     compiled: &Config<N>,
+) -> Result<(), Error> {
+    let input = unify_delimiters(
+        trim_whitespace(input, compiled),
+        compiled.unified_delimiter(),
+    );
+    validate_unified::<N, false>(&input, compiled, &mut Vec::new())
+}
+
+/// Validates a string and returns its canonical form: the lowercase string, with
+/// delimiters unified as configured, that decoding its encoding would produce.
+///
+/// Lowercasing is fused into the character check: each 8-byte chunk, or each byte of the
+/// remainder, is written lowercased to the output right after it is validated, so the
+/// input is scanned once and already lowercase input costs one copy.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl_validate::{config::Config, validate_and_canonicalize};
+///
+/// let config = Config::<16>::default();
+/// assert_eq!(validate_and_canonicalize::<16>("Hello-World", &config).unwrap(), "hello-world");
+/// ```
+#[inline]
+pub fn validate_and_canonicalize<const N: usize>(
+    input: &str,
+    config: &Config<N>,
+) -> Result<String, Error> {
+    let input = unify_delimiters(trim_whitespace(input, config), config.unified_delimiter());
+    let mut canonical = Vec::new();
+    validate_unified::<N, true>(&input, config, &mut canonical)?;
+    // SAFETY: validation only accepts ASCII, and lowercasing keeps it ASCII.
+    Ok(unsafe { String::from_utf8_unchecked(canonical) })
+}
+
+/// Validates `input`, also pushing its lowercased bytes to `lower` when `LOWER` is set.
+#[inline(always)]
+fn validate_unified<const N: usize, const LOWER: bool>(
+    input: &str,
+    compiled: &Config<N>,
+    lower: &mut Vec<u8>,
+) -> Result<(), Error> {
+    validate_characters_and_delimiters::<N, LOWER>(input, compiled, lower)?;
+    if let Some(rule) = compiled.segment_rule() {
+        validate_segments(input, &rule)?;
+    }
+    if let Some(min) = compiled.min_distinct_chars() {
+        validate_distinct_chars(input, min)?;
+    }
+    validate_reserved(input, compiled.reserved())?;
+    Ok(())
+}
+
+/// Trims leading and trailing ASCII whitespace from `input` if the config enables
+/// [`ConfigBuilder::trim_whitespace`](config::ConfigBuilder::trim_whitespace).
+#[inline]
+pub fn trim_whitespace<'a, const N: usize>(input: &'a str, config: &Config<N>) -> &'a str {
+    if config.trim_whitespace() {
+        input.trim_ascii()
+    } else {
+        input
+    }
+}
+
+/// Rewrites every hyphen and underscore in `input` to `unified`, as configured by
+/// [`ConfigBuilder::unify_delimiters`](config::ConfigBuilder::unify_delimiters).
+///
+/// Borrows `input` unchanged when `unified` is `None` or there is nothing to rewrite.
+pub fn unify_delimiters(input: &str, unified: Option<char>) -> Cow<'_, str> {
+    let (from, to) = match unified {
+        Some('-') => ('_', "-"),
+        Some('_') => ('-', "_"),
+        _ => return Cow::Borrowed(input),
+    };
+    if input.contains(from) {
+        Cow::Owned(input.replace(from, to))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Validates the delimiter-separated segments of a string against a [`SegmentRule`].
+///
+/// This only checks the segment structure; character and delimiter rules are not applied.
+/// Segment lengths are checked first, in order, followed by the segment count.
+///
+/// A segment outside the uniform length bounds yields [`Error::SegmentLength`] with its
+/// length, and one outside its [`position_lengths`](SegmentRule::position_lengths) entry
+/// yields [`Error::SegmentPositionLength`] with its position.
+pub fn validate_segments(input: &str, rule: &SegmentRule) -> Result<(), Error> {
+    let delimiter = rule.delimiter();
+    let positions = rule.position_lengths();
+    let mut count = 0usize;
+
+    for segment in input.split(delimiter) {
+        let len = segment.len();
+        if rule.min_segment_length().is_some_and(|min| len < min)
+            || rule.max_segment_length().is_some_and(|max| len > max)
+        {
+            return Err(Error::SegmentLength(len));
+        }
+        if positions
+            .get(count)
+            .is_some_and(|&(min, max)| len < min || len > max)
+        {
+            return Err(Error::SegmentPositionLength(count));
+        }
+        count += 1;
+    }
+
+    if rule.min_segments().is_some_and(|min| count < min)
+        || rule.max_segments().is_some_and(|max| count > max)
+    {
+        return Err(Error::SegmentCount(count));
+    }
+
+    Ok(())
+}
+
+/// Checks that `input` has at least `min` distinct characters, as configured by
+/// [`ConfigBuilder::min_distinct_chars`](config::ConfigBuilder::min_distinct_chars).
+///
+/// Letters are counted case-insensitively. The check is a single pass over the bytes,
+/// recording each one in a 128-bit presence bitmap, so it expects ASCII input as accepted
+/// by the character checks.
+pub fn validate_distinct_chars(input: &str, min: usize) -> Result<(), Error> {
+    let mut seen = 0u128;
+    for b in input.bytes() {
+        seen |= distinct_char_bit(b);
+    }
+    let distinct = seen.count_ones() as usize;
+    if distinct < min {
+        return Err(Error::InsufficientVariety(distinct));
+    }
+    Ok(())
+}
+
+/// Returns the bit recording `b` in a distinct character bitmap, folding ASCII case.
+#[inline(always)]
+fn distinct_char_bit(b: u8) -> u128 {
+    1 << (b.to_ascii_lowercase() & 0x7F)
+}
+
+/// Checks that `input` is not one of the `reserved` identifiers, as configured by
+/// [`ConfigBuilder::reserved`](config::ConfigBuilder::reserved).
+///
+/// The comparison folds ASCII case, matching the lowercase form HexaURL decodes to.
+pub fn validate_reserved(input: &str, reserved: &[&str]) -> Result<(), Error> {
+    if reserved.iter().any(|word| word.eq_ignore_ascii_case(input)) {
+        return Err(Error::Reserved);
+    }
+    Ok(())
+}
+
+/// Returns the error for the first byte of `bytes` rejected by `validator`, for reporting
+/// a block that failed a SWAR or SIMD check with the same error as the scalar path.
+#[cold]
+fn chunk_error(bytes: &[u8], validator: fn(u8) -> Result<(), Error>) -> Error {
+    bytes
+        .iter()
+        .find_map(|&b| validator(b).err())
+        .unwrap_or(Error::InvalidCharacter)
+}
+
+/// Runs the length, character and delimiter checks.
+///
+/// With `LOWER` set, every validated chunk and remainder byte is pushed lowercased to
+/// `lower`, so canonicalization shares the character scan. The SIMD blocks are skipped in
+/// that mode, since they do not produce output.
+#[inline(always)]
+fn validate_characters_and_delimiters<const N: usize, const LOWER: bool>(
+    input: &str,
+    compiled: &Config<N>,
+    lower: &mut Vec<u8>,
 ) -> Result<(), Error> {
     let len = input.len();
 
@@ -152,16 +386,40 @@ pub fn validate_with_compiled_config<const N: usize>(
     let mut has_hyphen = false;
     let mut has_underscore = false;
 
+    if LOWER {
+        lower.reserve_exact(len);
+    }
+    // Pushes a validated chunk to `lower`, lowercased.
+    let mut push_chunk = |val: u64| {
+        if LOWER {
+            lower.extend_from_slice(&validate_swar::to_ascii_lowercase(val).to_ne_bytes());
+        }
+    };
+
     match composition {
         Composition::Alphanumeric => {
+            #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+            let mut i = if LOWER {
+                0
+            } else {
+                match validate_simd::validate_blocks_alnum(bytes) {
+                    Some(consumed) => consumed,
+                    None => return Err(chunk_error(bytes, validate_char::validate_alphanumeric)),
+                }
+            };
+            #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
             let mut i = 0usize;
             while i < chunk_end {
                 // SAFETY: `i + 8 <= len` guarantees this read is in-bounds.
                 let val = unsafe { core::ptr::read_unaligned(ptr.add(i).cast::<u64>()) };
                 let (valid, _, _) = validate_swar::validate_chunk_alnum(val);
                 if !valid {
-                    return Err(Error::InvalidCharacter);
+                    return Err(chunk_error(
+                        &bytes[i..i + 8],
+                        validate_char::validate_alphanumeric,
+                    ));
                 }
+                push_chunk(val);
                 i += 8;
             }
         }
@@ -172,9 +430,13 @@ pub fn validate_with_compiled_config<const N: usize>(
                 let val = unsafe { core::ptr::read_unaligned(ptr.add(i).cast::<u64>()) };
                 let (valid, h, _) = validate_swar::validate_chunk_hyphen(val);
                 if !valid {
-                    return Err(Error::InvalidCharacter);
+                    return Err(chunk_error(
+                        &bytes[i..i + 8],
+                        validate_char::validate_alphanumeric_with_hyphen,
+                    ));
                 }
                 has_hyphen |= h;
+                push_chunk(val);
                 i += 8;
             }
         }
@@ -185,9 +447,13 @@ pub fn validate_with_compiled_config<const N: usize>(
                 let val = unsafe { core::ptr::read_unaligned(ptr.add(i).cast::<u64>()) };
                 let (valid, _, u) = validate_swar::validate_chunk_underscore(val);
                 if !valid {
-                    return Err(Error::InvalidCharacter);
+                    return Err(chunk_error(
+                        &bytes[i..i + 8],
+                        validate_char::validate_alphanumeric_with_underscore,
+                    ));
                 }
                 has_underscore |= u;
+                push_chunk(val);
                 i += 8;
             }
         }
@@ -198,24 +464,35 @@ pub fn validate_with_compiled_config<const N: usize>(
                 let val = unsafe { core::ptr::read_unaligned(ptr.add(i).cast::<u64>()) };
                 let (valid, h, u) = validate_swar::validate_chunk_both(val);
                 if !valid {
-                    return Err(Error::InvalidCharacter);
+                    return Err(chunk_error(
+                        &bytes[i..i + 8],
+                        validate_char::validate_alphanumeric_with_hyphen_or_underscore,
+                    ));
                 }
                 has_hyphen |= h;
                 has_underscore |= u;
+                push_chunk(val);
                 i += 8;
             }
         }
     }
 
+    let mut push_byte = |b: u8| {
+        if LOWER {
+            lower.push(b.to_ascii_lowercase());
+        }
+    };
     match composition {
         Composition::Alphanumeric => {
             for &b in &bytes[chunk_end..] {
                 validate_char::validate_alphanumeric(b)?;
+                push_byte(b);
             }
         }
         Composition::AlphanumericHyphen => {
             for &b in &bytes[chunk_end..] {
                 validate_char::validate_alphanumeric_with_hyphen(b)?;
+                push_byte(b);
                 if b == b'-' {
                     has_hyphen = true;
                 }
@@ -224,6 +501,7 @@ pub fn validate_with_compiled_config<const N: usize>(
         Composition::AlphanumericUnderscore => {
             for &b in &bytes[chunk_end..] {
                 validate_char::validate_alphanumeric_with_underscore(b)?;
+                push_byte(b);
                 if b == b'_' {
                     has_underscore = true;
                 }
@@ -232,6 +510,7 @@ pub fn validate_with_compiled_config<const N: usize>(
         Composition::AlphanumericHyphenUnderscore => {
             for &b in &bytes[chunk_end..] {
                 validate_char::validate_alphanumeric_with_hyphen_or_underscore(b)?;
+                push_byte(b);
                 if b == b'-' {
                     has_hyphen = true;
                 } else if b == b'_' {
@@ -269,27 +548,26 @@ pub fn validate_with_compiled_config<const N: usize>(
         Composition::AlphanumericHyphen => {
             if has_hyphen {
                 // Check consecutive hyphens.
-                let rules = compiled.delimiter_rules();
-                if !rules.allow_consecutive_hyphens() && has_consecutive_delimiter(bytes, b'-') {
+                let rules = compiled.delimiter_rules_ref();
+                if exceeds_delimiter_run(bytes, b'-', rules.max_hyphen_run()) {
                     return Err(Error::ConsecutiveHyphens);
                 }
             }
         }
         Composition::AlphanumericUnderscore => {
             if has_underscore {
-                let rules = compiled.delimiter_rules();
-                if !rules.allow_consecutive_underscores() && has_consecutive_delimiter(bytes, b'_')
-                {
+                let rules = compiled.delimiter_rules_ref();
+                if exceeds_delimiter_run(bytes, b'_', rules.max_underscore_run()) {
                     return Err(Error::ConsecutiveUnderscores);
                 }
             }
         }
         Composition::AlphanumericHyphenUnderscore => {
-            let rules = compiled.delimiter_rules();
+            let rules = compiled.delimiter_rules_ref();
             if let Some(err) = first_mixed_delimiter_violation(
                 bytes,
-                !rules.allow_consecutive_hyphens(),
-                !rules.allow_consecutive_underscores(),
+                rules.max_hyphen_run(),
+                rules.max_underscore_run(),
                 !rules.allow_adjacent_hyphen_underscore(),
             ) {
                 return Err(err);
@@ -298,7 +576,7 @@ pub fn validate_with_compiled_config<const N: usize>(
     }
 
     // Validate leading/trailing delimiter characters.
-    let rules = compiled.delimiter_rules();
+    let rules = compiled.delimiter_rules_ref();
     if (input.starts_with('-') && !rules.allow_leading_hyphens())
         || (input.ends_with('-') && !rules.allow_trailing_hyphens())
     {
@@ -323,7 +601,7 @@ pub fn validate_with_compiled_config<const N: usize>(
 /// - `N`: The byte size of HexaURL encoded string.
 #[inline]
 pub fn validate_minimal_config<const N: usize>(input: &str) -> Result<(), Error> {
-    let max = calc_str_len(N);
+    let max = max_str_len(N);
 
     // Check maximum length.
     if input.len() > max {
@@ -341,7 +619,10 @@ pub fn validate_minimal_config<const N: usize>(input: &str) -> Result<(), Error>
         let val = unsafe { core::ptr::read_unaligned(ptr.add(i).cast::<u64>()) };
         let (valid, _, _) = validate_swar::validate_chunk_both(val);
         if !valid {
-            return Err(Error::InvalidCharacter);
+            return Err(chunk_error(
+                &bytes[i..i + 8],
+                validate_char::validate_alphanumeric_with_hyphen_or_underscore,
+            ));
         }
         i += 8;
     }
@@ -353,6 +634,66 @@ pub fn validate_minimal_config<const N: usize>(input: &str) -> Result<(), Error>
     Ok(())
 }
 
+/// Bitmap of the ASCII codes accepted by [`validate_ascii_charset`]: `-`, `0-9`, `A-Z`,
+/// `_` and `a-z`.
+const CHARSET_BITMAP: u128 = 0x07FFFFFE_87FFFFFE_03FF2000_00000000;
+
+/// Returns whether `c` is in the SIXBIT alphabet: an ASCII letter of either case, a digit,
+/// `-` or `_`.
+///
+/// Unlike the `validate_char` functions this ignores the composition, and it can be used
+/// in `const` contexts.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl_validate::is_sixbit_char;
+///
+/// const VALID: bool = is_sixbit_char(b'Q') && is_sixbit_char(b'_');
+/// assert!(VALID);
+/// assert!(!is_sixbit_char(b'.'));
+/// ```
+#[inline(always)]
+pub const fn is_sixbit_char(c: u8) -> bool {
+    c < 128 && (CHARSET_BITMAP >> c) & 1 == 1
+}
+
+/// Validates a byte slice already known to be ASCII against the minimal configuration.
+///
+/// This is the leanest validation tier, for read paths such as map lookups that have
+/// already checked [`str::is_ascii`]. It checks the maximum length, then runs the SWAR
+/// character check over 8-byte chunks and a bitmap test over the remaining bytes. There is
+/// no separate ASCII pass; non-ASCII bytes are still rejected, just not singled out.
+///
+/// # Const Parameters
+/// - `N`: The byte size of HexaURL encoded string.
+#[inline]
+pub fn validate_ascii_charset<const N: usize>(input: &[u8]) -> Result<(), Error> {
+    let max = max_str_len(N);
+    if input.len() > max {
+        return Err(Error::StringTooLong(max));
+    }
+
+    let mut chunks = input.chunks_exact(8);
+    for chunk in &mut chunks {
+        let val = u64::from_ne_bytes(chunk.try_into().expect("chunk has 8 bytes"));
+        if !validate_swar::validate_chunk_both(val).0 {
+            return Err(chunk_error(
+                chunk,
+                validate_char::validate_alphanumeric_with_hyphen_or_underscore,
+            ));
+        }
+    }
+
+    for &b in chunks.remainder() {
+        if !is_sixbit_char(b) {
+            return Err(Error::invalid_character(b));
+        }
+    }
+
+    Ok(())
+}
+
 /// Checks if the input string is safe for HexaURL encoding without risk of panics or conflicts.
 ///
 /// This function is optimized for speed by performing minimal checks:
@@ -392,14 +733,14 @@ pub fn validate_minimal_config<const N: usize>(input: &str) -> Result<(), Error>
 /// ```
 #[inline(always)]
 pub const fn check_encoding_safe<const N: usize>(input: &str) -> Result<(), Error> {
-    if input.len() <= calc_str_len(N) {
+    if input.len() <= max_str_len(N) {
         if input.is_ascii() {
             Ok(())
         } else {
             Err(Error::InvalidCharacter)
         }
     } else {
-        Err(Error::StringTooLong(calc_str_len(N)))
+        Err(Error::StringTooLong(max_str_len(N)))
     }
 }
 
@@ -414,12 +755,39 @@ pub const fn validate_for_lookup<const N: usize>(input: &str) -> Result<(), Erro
 #[cfg(test)]
 mod tests {
     use super::*;
+    use config::DelimiterRules;
     use error::Error;
 
     fn compiled(raw: Config<16>) -> Config<16> {
         compile_config::<16>(raw).unwrap()
     }
 
+    #[test]
+    fn test_ascii_charset_matches_minimal_config() {
+        for len in [0, 1, 7, 8, 9, 16, 21] {
+            for byte in 0..=127u8 {
+                let mut input = "a".repeat(len).into_bytes();
+                if let Some(last) = input.last_mut() {
+                    *last = byte;
+                }
+                let input = String::from_utf8(input).unwrap();
+                assert_eq!(
+                    validate_ascii_charset::<16>(input.as_bytes()),
+                    validate_minimal_config::<16>(&input),
+                    "len {len}, byte {byte:#04x}",
+                );
+            }
+        }
+        assert_eq!(
+            validate_ascii_charset::<16>(&[b'a'; 22]),
+            Err(Error::StringTooLong(21))
+        );
+        assert_eq!(
+            validate_ascii_charset::<16>(b"abc\xe1"),
+            Err(Error::InvalidCharacter)
+        );
+    }
+
     // Test that non-ASCII characters are rejected.
     #[test]
     fn test_non_ascii() {
@@ -473,6 +841,29 @@ mod tests {
         assert_eq!(result, Err(Error::InvalidCharacter));
     }
 
+    // Test long alphanumeric input, covering block, chunk and tail validation alike.
+    #[test]
+    fn test_alphanumeric_long() {
+        let config = Config::<64>::builder()
+            .composition(Composition::Alphanumeric)
+            .build()
+            .unwrap();
+        let input =
+            "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789abcdefghij";
+        assert_eq!(validate_with_config::<64>(input, &config), Ok(()));
+
+        for pos in 0..input.len() {
+            let mut invalid = input.as_bytes().to_vec();
+            invalid[pos] = b'-';
+            let invalid = std::str::from_utf8(&invalid).unwrap();
+            assert_eq!(
+                validate_with_config::<64>(invalid, &config),
+                Err(Error::InvalidCharacter),
+                "invalid byte at {pos}",
+            );
+        }
+    }
+
     // Test valid input when hyphens are explicitly allowed.
     #[test]
     fn test_alphanumeric_hyphen_valid() {
@@ -538,6 +929,54 @@ mod tests {
         assert_eq!(result, Err(Error::ConsecutiveUnderscores));
     }
 
+    // Test that runs of delimiters are limited by `max_consecutive_*`.
+    #[test]
+    fn test_max_consecutive_delimiters() {
+        let config = compiled(
+            Config::builder()
+                .composition(Composition::AlphanumericHyphen)
+                .delimiter(Some(
+                    DelimiterRules::builder()
+                        .max_consecutive_hyphens(Some(2))
+                        .build(),
+                ))
+                .build()
+                .unwrap(),
+        );
+        assert!(validate_with_config::<16>("ab--c-d--e", &config).is_ok());
+        assert_eq!(
+            validate_with_config::<16>("ab---cd", &config),
+            Err(Error::ConsecutiveHyphens)
+        );
+
+        let config = compiled(
+            Config::builder()
+                .composition(Composition::AlphanumericHyphenUnderscore)
+                .delimiter(Some(
+                    DelimiterRules::builder()
+                        .allow_consecutive_hyphens(true)
+                        .max_consecutive_hyphens(Some(1))
+                        .max_consecutive_underscores(Some(3))
+                        .build(),
+                ))
+                .build()
+                .unwrap(),
+        );
+        assert!(validate_with_config::<16>("ab___cd", &config).is_ok());
+        assert_eq!(
+            validate_with_config::<16>("ab____cd", &config),
+            Err(Error::ConsecutiveUnderscores)
+        );
+        assert_eq!(
+            validate_with_config::<16>("ab--cd", &config),
+            Err(Error::ConsecutiveHyphens)
+        );
+        assert_eq!(
+            validate_with_config::<16>("ab_-cd", &config),
+            Err(Error::AdjacentHyphenUnderscore)
+        );
+    }
+
     // Test that a leading or trailing underscore causes an error.
     #[test]
     fn test_leading_trailing_underscore() {
@@ -584,6 +1023,125 @@ mod tests {
         );
     }
 
+    fn three_segments() -> Config<16> {
+        compiled(
+            Config::builder()
+                .segments(Some(
+                    SegmentRule::builder()
+                        .min_segments(Some(3))
+                        .max_segments(Some(3))
+                        .min_segment_length(Some(1))
+                        .max_segment_length(Some(4))
+                        .build(),
+                ))
+                .build()
+                .unwrap(),
+        )
+    }
+
+    // Test that inputs with the configured segment shape are accepted.
+    #[test]
+    fn test_segments_valid() {
+        let config = three_segments();
+        assert!(validate_with_config::<16>("ab-cd-ef", &config).is_ok());
+        assert!(validate_with_config::<16>("abcd-e-f12", &config).is_ok());
+    }
+
+    // Test that too few or too many segments are rejected.
+    #[test]
+    fn test_segments_count() {
+        let config = three_segments();
+        assert_eq!(
+            validate_with_config::<16>("ab-cd", &config),
+            Err(Error::SegmentCount(2))
+        );
+        assert_eq!(
+            validate_with_config::<16>("abc", &config),
+            Err(Error::SegmentCount(1))
+        );
+        assert_eq!(
+            validate_with_config::<16>("ab-cd-ef-gh", &config),
+            Err(Error::SegmentCount(4))
+        );
+    }
+
+    // Test that an over-long segment is rejected.
+    #[test]
+    fn test_segments_length() {
+        let config = three_segments();
+        assert_eq!(
+            validate_with_config::<16>("ab-cdefg-h", &config),
+            Err(Error::SegmentLength(5))
+        );
+        // Delimiter rules are reported before segment rules.
+        assert_eq!(
+            validate_with_config::<16>("ab--cd", &config),
+            Err(Error::ConsecutiveHyphens)
+        );
+    }
+
+    // Test per-position segment lengths, reported with the segment position.
+    #[test]
+    fn test_segments_position_lengths() {
+        let config = compiled(
+            Config::builder()
+                .segments(Some(
+                    SegmentRule::builder()
+                        .max_segment_length(Some(8))
+                        .position_lengths(&[(3, 3), (1, 6)])
+                        .build(),
+                ))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(validate_with_config::<16>("usw-api-01", &config), Ok(()));
+        assert_eq!(
+            validate_with_config::<16>("euro-api-01", &config),
+            Err(Error::SegmentPositionLength(0))
+        );
+        assert_eq!(
+            validate_with_config::<16>("eu-api-01", &config),
+            Err(Error::SegmentPositionLength(0))
+        );
+        assert_eq!(
+            validate_with_config::<16>("usw-gateway-01", &config),
+            Err(Error::SegmentPositionLength(1))
+        );
+        // Uniform bounds are checked first and report the length.
+        assert_eq!(
+            validate_with_config::<16>("usw-endpoints-1", &config),
+            Err(Error::SegmentLength(9))
+        );
+        // Positions without an entry are only checked against the uniform bounds.
+        assert_eq!(
+            validate_with_config::<16>("usw-api-instance", &config),
+            Ok(())
+        );
+
+        // An empty first segment breaks the uniform bounds, not the bound for position 0.
+        let config = compiled(
+            Config::builder()
+                .composition(Composition::AlphanumericHyphen)
+                .delimiter(Some(DelimiterRules::all_allowed()))
+                .segments(Some(
+                    SegmentRule::builder()
+                        .min_segment_length(Some(1))
+                        .position_lengths(&[(3, 3)])
+                        .build(),
+                ))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            validate_with_config::<16>("-api", &config),
+            Err(Error::SegmentLength(0))
+        );
+        assert_eq!(
+            validate_with_config::<16>("a-api", &config),
+            Err(Error::SegmentPositionLength(0))
+        );
+    }
+
     // Test that adjacent different delimiters (hyphen and underscore) are rejected.
     #[test]
     fn test_alphanumeric_hyphen_underscore_adjacent() {
@@ -597,4 +1155,294 @@ mod tests {
         let result = validate_with_config::<16>("abc-_123", &config);
         assert_eq!(result, Err(Error::AdjacentHyphenUnderscore));
     }
+
+    // Test that unified delimiters are rewritten before validation.
+    #[test]
+    fn test_unify_delimiters() {
+        let config = compiled(
+            Config::builder()
+                .unify_delimiters(Some('-'))
+                .build()
+                .unwrap(),
+        );
+        // Underscores are not allowed by the composition, but become hyphens first.
+        assert_eq!(validate_with_config::<16>("foo_bar", &config), Ok(()));
+        assert_eq!(
+            validate_with_config::<16>("foo-_bar", &config),
+            Err(Error::ConsecutiveHyphens)
+        );
+
+        assert!(matches!(
+            unify_delimiters("a-b", None),
+            Cow::Borrowed("a-b")
+        ));
+        assert!(matches!(
+            unify_delimiters("a-b", Some('-')),
+            Cow::Borrowed("a-b")
+        ));
+        assert_eq!(unify_delimiters("a-b_c", Some('_')), "a_b_c");
+    }
+
+    #[test]
+    fn test_min_distinct_chars() {
+        let config = compiled(
+            Config::builder()
+                .min_distinct_chars(Some(2))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            validate_with_config::<16>("aaaaaa", &config),
+            Err(Error::InsufficientVariety(1))
+        );
+        assert_eq!(
+            validate_with_config::<16>("aaaAAA", &config),
+            Err(Error::InsufficientVariety(1))
+        );
+        assert_eq!(validate_with_config::<16>("aaaa-aaaa", &config), Ok(()));
+        assert_eq!(validate_with_config::<16>("user-42", &config), Ok(()));
+
+        assert_eq!(
+            validate_distinct_chars("aaaa-aaaa", 3),
+            Err(Error::InsufficientVariety(2))
+        );
+        assert_eq!(validate_distinct_chars("abc", 3), Ok(()));
+        assert_eq!(validate_distinct_chars("", 0), Ok(()));
+    }
+
+    #[test]
+    fn test_consecutive_delimiter_across_chunks() {
+        // Every position of a pair, including pairs spanning a chunk boundary and the remainder.
+        for at in 0..18 {
+            let mut bytes = *b"a-b-c-d-e-f-g-h-i-j";
+            bytes[at] = b'-';
+            bytes[at + 1] = b'-';
+            assert!(
+                has_consecutive_delimiter(&bytes, b'-', validate_swar::has_consecutive_hyphens),
+                "{at}"
+            );
+            bytes
+                .iter_mut()
+                .for_each(|b| *b = if *b == b'-' { b'_' } else { *b });
+            assert!(
+                has_consecutive_delimiter(&bytes, b'_', validate_swar::has_consecutive_underscores),
+                "{at}"
+            );
+        }
+        assert!(!has_consecutive_delimiter(
+            b"a-b-c-d-e-f-g-h-i-j",
+            b'-',
+            validate_swar::has_consecutive_hyphens
+        ));
+    }
+
+    #[test]
+    fn test_validate_and_canonicalize() {
+        let config = compiled(Config::default());
+        assert_eq!(
+            validate_and_canonicalize::<16>("hello-world", &config),
+            Ok("hello-world".to_owned())
+        );
+        assert_eq!(
+            validate_and_canonicalize::<16>("Hello-WORLD", &config),
+            Ok("hello-world".to_owned())
+        );
+        assert_eq!(
+            validate_and_canonicalize::<16>("-hello", &config),
+            Err(Error::LeadingTrailingHyphen)
+        );
+
+        let unified = compiled(
+            Config::builder()
+                .composition(Composition::AlphanumericHyphenUnderscore)
+                .unify_delimiters(Some('-'))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            validate_and_canonicalize::<16>("Foo_Bar", &unified),
+            Ok("foo-bar".to_owned())
+        );
+
+        // Long inputs cover the chunked path and its scalar remainder.
+        for composition in [
+            Composition::Alphanumeric,
+            Composition::AlphanumericHyphen,
+            Composition::AlphanumericUnderscore,
+            Composition::AlphanumericHyphenUnderscore,
+        ] {
+            let config = compiled(Config::builder().composition(composition).build().unwrap());
+            let input = "AbCdEfGhIjKlMnOpQrSt9";
+            assert_eq!(
+                validate_and_canonicalize::<16>(input, &config),
+                Ok(input.to_ascii_lowercase())
+            );
+            assert_eq!(
+                validate_and_canonicalize::<16>("AbCdEfGh IjKlMnOp", &config),
+                Err(Error::InvalidCharacter)
+            );
+        }
+    }
+
+    #[test]
+    fn test_reserved() {
+        let config = compiled(Config::builder().reserved(&["admin"]).build().unwrap());
+        assert_eq!(
+            validate_with_config::<16>("admin", &config),
+            Err(Error::Reserved)
+        );
+        assert_eq!(
+            validate_with_config::<16>("Admin", &config),
+            Err(Error::Reserved)
+        );
+        assert_eq!(validate_with_config::<16>("administrator", &config), Ok(()));
+        assert_eq!(
+            validate_with_config::<16>("admin", &compiled(Config::default())),
+            Ok(())
+        );
+
+        assert_eq!(validate_reserved("ROOT", &["root"]), Err(Error::Reserved));
+        assert_eq!(validate_reserved("root", &[]), Ok(()));
+    }
+
+    #[test]
+    fn test_control_character() {
+        for input in ["foo\tbar", "foo\n", "\x7Ffoo", "long-enough\tinput"] {
+            assert_eq!(validate::<16>(input), Err(Error::ControlCharacter));
+            assert_eq!(
+                validate_minimal_config::<16>(input),
+                Err(Error::ControlCharacter)
+            );
+            assert_eq!(
+                validate_ascii_charset::<16>(input.as_bytes()),
+                Err(Error::ControlCharacter)
+            );
+        }
+        for input in ["foo!bar", "foo bar", "long-enough!input"] {
+            assert_eq!(validate::<16>(input), Err(Error::InvalidCharacter));
+        }
+
+        let config = compiled(
+            Config::builder()
+                .composition(Composition::Alphanumeric)
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            validate_with_config::<16>("alphanumeric\t", &config),
+            Err(Error::ControlCharacter)
+        );
+        assert_eq!(
+            validate_with_config::<16>("alphanumeric-", &config),
+            Err(Error::InvalidCharacter)
+        );
+        assert_eq!(Error::ControlCharacter.kind(), ErrorKind::CharacterError);
+    }
+
+    #[test]
+    fn test_capacity_conversions() {
+        for (bytes, len) in [
+            (8, 10),
+            (16, 21),
+            (32, 42),
+            (64, 85),
+            (128, 170),
+            (256, 341),
+        ] {
+            assert_eq!(max_str_len(bytes), len);
+            assert_eq!(min_byte_capacity(len), bytes);
+        }
+        for len in 0..400 {
+            let bytes = min_byte_capacity(len);
+            assert!(max_str_len(bytes) >= len);
+            assert!(bytes == 0 || max_str_len(bytes - 1) < len);
+        }
+    }
+
+    #[test]
+    fn test_is_sixbit_char() {
+        const ACCEPTED: [bool; 7] = [
+            is_sixbit_char(b'a'),
+            is_sixbit_char(b'z'),
+            is_sixbit_char(b'A'),
+            is_sixbit_char(b'Z'),
+            is_sixbit_char(b'0'),
+            is_sixbit_char(b'-'),
+            is_sixbit_char(b'_'),
+        ];
+        const REJECTED: [bool; 7] = [
+            is_sixbit_char(b'.'),
+            is_sixbit_char(b' '),
+            is_sixbit_char(b'@'),
+            is_sixbit_char(b'~'),
+            is_sixbit_char(0),
+            is_sixbit_char(0x7F),
+            is_sixbit_char(0xE9),
+        ];
+        assert_eq!(ACCEPTED, [true; 7]);
+        assert_eq!(REJECTED, [false; 7]);
+
+        for c in 0..=u8::MAX {
+            let expected = c.is_ascii_alphanumeric() || c == b'-' || c == b'_';
+            assert_eq!(is_sixbit_char(c), expected, "{c}");
+        }
+    }
+
+    #[test]
+    fn test_trim_whitespace() {
+        let config = compiled(Config::builder().trim_whitespace(true).build().unwrap());
+        assert_eq!(validate_with_config::<16>(" foo ", &config), Ok(()));
+        assert_eq!(validate_with_config::<16>("\tfoo\n", &config), Ok(()));
+        assert_eq!(
+            validate_with_config::<16>("f o o", &config),
+            Err(Error::InvalidCharacter)
+        );
+        assert_eq!(
+            validate_with_config::<16>("  ", &config),
+            Err(Error::StringTooShort(3))
+        );
+        assert_eq!(validate::<16>(" foo "), Err(Error::InvalidCharacter));
+        assert_eq!(
+            validate_and_canonicalize::<16>(" Foo ", &config).unwrap(),
+            "foo"
+        );
+    }
+
+    #[test]
+    fn test_error_conversions() {
+        let err = Error::InvalidCharacter.into_io_error();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), Error::InvalidCharacter.to_string());
+        assert_eq!(
+            err.get_ref().and_then(|e| e.downcast_ref::<Error>()),
+            Some(&Error::InvalidCharacter)
+        );
+
+        let err: std::io::Error = Error::StringTooLong(21).into();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        let boxed: Box<dyn std::error::Error + Send + Sync> = Error::InvalidByte.into();
+        assert_eq!(boxed.to_string(), Error::InvalidByte.to_string());
+    }
+
+    #[test]
+    fn test_error_kind() {
+        assert_eq!(Error::StringTooLong(16).kind(), ErrorKind::LengthError);
+        assert_eq!(Error::BytesTooShort(1).kind(), ErrorKind::LengthError);
+        assert_eq!(Error::InvalidCharacter.kind(), ErrorKind::CharacterError);
+        assert_eq!(Error::Reserved.kind(), ErrorKind::CharacterError);
+        assert_eq!(Error::ConsecutiveHyphens.kind(), ErrorKind::DelimiterError);
+        assert_eq!(Error::SegmentLength(0).kind(), ErrorKind::DelimiterError);
+        assert_eq!(
+            Error::SegmentPositionLength(0).kind(),
+            ErrorKind::DelimiterError
+        );
+        assert_eq!(Error::InvalidConfig(1, 2).kind(), ErrorKind::ConfigError);
+        assert_eq!(Error::InvalidLength.kind(), ErrorKind::LengthMismatch);
+
+        assert_eq!(
+            validate::<16>("hello--world").unwrap_err().kind(),
+            ErrorKind::DelimiterError
+        );
+    }
 }
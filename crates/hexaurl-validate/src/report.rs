@@ -0,0 +1,91 @@
+//! Aggregate metrics for a single candidate identifier.
+
+use crate::{Config, Error, distinct_char_bit, validate_with_config};
+
+/// Metrics collected by [`analyze`], for bucketing candidate identifiers in bulk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Length of the input in bytes.
+    pub length: usize,
+    /// Number of hyphens.
+    pub hyphen_count: usize,
+    /// Number of underscores.
+    pub underscore_count: usize,
+    /// Number of distinct characters, counting letters case-insensitively as
+    /// [`validate_distinct_chars`](crate::validate_distinct_chars) does.
+    pub distinct_chars: usize,
+    /// Whether the input passes [`validate_with_config`].
+    pub is_valid: bool,
+    /// The error [`validate_with_config`] reports, if any.
+    pub first_error: Option<Error>,
+}
+
+/// Collects a [`ValidationReport`] for `input` under `config`.
+///
+/// The counts come from a single pass over the bytes, followed by the regular
+/// [`validate_with_config`] for the verdict. The counts describe `input` as given, before
+/// any delimiter unification.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl_validate::{analyze, config::Config};
+///
+/// let report = analyze::<16>("user-42", &Config::default());
+/// assert_eq!(report.hyphen_count, 1);
+/// assert!(report.is_valid);
+/// ```
+pub fn analyze<const N: usize>(input: &str, config: &Config<N>) -> ValidationReport {
+    let mut hyphen_count = 0;
+    let mut underscore_count = 0;
+    let mut seen = 0u128;
+    for b in input.bytes() {
+        match b {
+            b'-' => hyphen_count += 1,
+            b'_' => underscore_count += 1,
+            _ => {}
+        }
+        seen |= distinct_char_bit(b);
+    }
+
+    let first_error = validate_with_config::<N>(input, config).err();
+    ValidationReport {
+        length: input.len(),
+        hyphen_count,
+        underscore_count,
+        distinct_chars: seen.count_ones() as usize,
+        is_valid: first_error.is_none(),
+        first_error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Composition;
+
+    #[test]
+    fn test_analyze() {
+        let config = Config::<16>::builder()
+            .composition(Composition::AlphanumericHyphenUnderscore)
+            .build()
+            .unwrap();
+        assert_eq!(
+            analyze::<16>("User-Name_42", &config),
+            ValidationReport {
+                length: 12,
+                hyphen_count: 1,
+                underscore_count: 1,
+                distinct_chars: 11,
+                is_valid: true,
+                first_error: None,
+            }
+        );
+
+        let report = analyze::<16>("Bad--Name", &config);
+        assert_eq!(report.hyphen_count, 2);
+        assert_eq!(report.distinct_chars, 7);
+        assert!(!report.is_valid);
+        assert_eq!(report.first_error, Some(Error::ConsecutiveHyphens));
+    }
+}
@@ -19,6 +19,7 @@ use crate::Error;
 ///
 /// - `Ok(())` if the character is an uppercase letter, lowercase letter, digit,
 ///   hyphen (`-`), or underscore (`_`).
+/// - `Err(Error::ControlCharacter)` for an ASCII control character.
 /// - `Err(Error::InvalidCharacter)` otherwise.
 #[inline(always)]
 pub const fn validate_alphanumeric_with_hyphen_or_underscore(code: u8) -> Result<(), Error> {
@@ -30,7 +31,7 @@ pub const fn validate_alphanumeric_with_hyphen_or_underscore(code: u8) -> Result
     {
         Ok(())
     } else {
-        Err(Error::InvalidCharacter)
+        Err(Error::invalid_character(code))
     }
 }
 
@@ -43,6 +44,7 @@ pub const fn validate_alphanumeric_with_hyphen_or_underscore(code: u8) -> Result
 /// # Returns
 ///
 /// - `Ok(())` if the character is an uppercase letter, lowercase letter, digit, or underscore (`_`).
+/// - `Err(Error::ControlCharacter)` for an ASCII control character.
 /// - `Err(Error::InvalidCharacter)` otherwise.
 #[inline(always)]
 pub const fn validate_alphanumeric_with_underscore(code: u8) -> Result<(), Error> {
@@ -53,7 +55,7 @@ pub const fn validate_alphanumeric_with_underscore(code: u8) -> Result<(), Error
     {
         Ok(())
     } else {
-        Err(Error::InvalidCharacter)
+        Err(Error::invalid_character(code))
     }
 }
 
@@ -66,6 +68,7 @@ pub const fn validate_alphanumeric_with_underscore(code: u8) -> Result<(), Error
 /// # Returns
 ///
 /// - `Ok(())` if the character is an uppercase letter, lowercase letter, digit, or hyphen (`-`).
+/// - `Err(Error::ControlCharacter)` for an ASCII control character.
 /// - `Err(Error::InvalidCharacter)` otherwise.
 #[inline(always)]
 pub const fn validate_alphanumeric_with_hyphen(code: u8) -> Result<(), Error> {
@@ -76,7 +79,7 @@ pub const fn validate_alphanumeric_with_hyphen(code: u8) -> Result<(), Error> {
     {
         Ok(())
     } else {
-        Err(Error::InvalidCharacter)
+        Err(Error::invalid_character(code))
     }
 }
 
@@ -89,6 +92,7 @@ pub const fn validate_alphanumeric_with_hyphen(code: u8) -> Result<(), Error> {
 /// # Returns
 ///
 /// - `Ok(())` if the character is an uppercase letter, lowercase letter, or digit.
+/// - `Err(Error::ControlCharacter)` for an ASCII control character.
 /// - `Err(Error::InvalidCharacter)` otherwise.
 #[inline(always)]
 pub const fn validate_alphanumeric(code: u8) -> Result<(), Error> {
@@ -98,7 +102,7 @@ pub const fn validate_alphanumeric(code: u8) -> Result<(), Error> {
     {
         Ok(())
     } else {
-        Err(Error::InvalidCharacter)
+        Err(Error::invalid_character(code))
     }
 }
 
@@ -121,6 +125,9 @@ mod tests {
         assert_eq!(validate_alphanumeric(b'-'), Err(Error::InvalidCharacter));
         assert_eq!(validate_alphanumeric(b'_'), Err(Error::InvalidCharacter));
         assert_eq!(validate_alphanumeric(b' '), Err(Error::InvalidCharacter));
+        assert_eq!(validate_alphanumeric(b'!'), Err(Error::InvalidCharacter));
+        assert_eq!(validate_alphanumeric(b'\t'), Err(Error::ControlCharacter));
+        assert_eq!(validate_alphanumeric(0x7F), Err(Error::ControlCharacter));
     }
 
     // Tests for validate_alphanumeric_with_hyphen: numbers, letters and hyphen are valid.
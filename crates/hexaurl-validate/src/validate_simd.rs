@@ -0,0 +1,130 @@
+use core::arch::x86_64::*;
+
+// Each range is shifted so that it starts at `i8::MIN`; a signed compare against the shifted
+// upper bound then acts as an unsigned range check.
+const LETTER_SHIFT: i8 = 0x80u8.wrapping_sub(b'a') as i8;
+const LETTER_LIMIT: i8 = (0x80u8 + 26) as i8;
+const DIGIT_SHIFT: i8 = 0x80u8.wrapping_sub(b'0') as i8;
+const DIGIT_LIMIT: i8 = (0x80u8 + 10) as i8;
+const CASE_BIT: i8 = 0x20;
+
+/// Validates the longest prefix of `bytes` made of whole SIMD blocks for `[0-9A-Za-z]`.
+///
+/// Returns the number of bytes consumed, which is a multiple of 16, or `None` if any of them
+/// is not alphanumeric. Blocks are 32 bytes wide when AVX2 is enabled at compile time.
+#[inline(always)]
+pub fn validate_blocks_alnum(bytes: &[u8]) -> Option<usize> {
+    let ptr = bytes.as_ptr();
+    let len = bytes.len();
+    let mut i = 0usize;
+
+    #[cfg(target_feature = "avx2")]
+    while i + 32 <= len {
+        // SAFETY: `i + 32 <= len` guarantees this read is in-bounds, and AVX2 is enabled.
+        if !unsafe { block32_is_alnum(ptr.add(i)) } {
+            return None;
+        }
+        i += 32;
+    }
+
+    while i + 16 <= len {
+        // SAFETY: `i + 16 <= len` guarantees this read is in-bounds, and SSE2 is part of the
+        // x86_64 baseline.
+        if !unsafe { block16_is_alnum(ptr.add(i)) } {
+            return None;
+        }
+        i += 16;
+    }
+
+    Some(i)
+}
+
+/// # Safety
+///
+/// `ptr` must be valid for reading 16 bytes.
+#[inline(always)]
+unsafe fn block16_is_alnum(ptr: *const u8) -> bool {
+    unsafe {
+        let v = _mm_loadu_si128(ptr.cast());
+        let lower = _mm_or_si128(v, _mm_set1_epi8(CASE_BIT));
+        let letter = _mm_cmpgt_epi8(
+            _mm_set1_epi8(LETTER_LIMIT),
+            _mm_add_epi8(lower, _mm_set1_epi8(LETTER_SHIFT)),
+        );
+        let digit = _mm_cmpgt_epi8(
+            _mm_set1_epi8(DIGIT_LIMIT),
+            _mm_add_epi8(v, _mm_set1_epi8(DIGIT_SHIFT)),
+        );
+        _mm_movemask_epi8(_mm_or_si128(letter, digit)) == 0xFFFF
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must be valid for reading 32 bytes.
+#[cfg(target_feature = "avx2")]
+#[inline(always)]
+unsafe fn block32_is_alnum(ptr: *const u8) -> bool {
+    unsafe {
+        let v = _mm256_loadu_si256(ptr.cast());
+        let lower = _mm256_or_si256(v, _mm256_set1_epi8(CASE_BIT));
+        let letter = _mm256_cmpgt_epi8(
+            _mm256_set1_epi8(LETTER_LIMIT),
+            _mm256_add_epi8(lower, _mm256_set1_epi8(LETTER_SHIFT)),
+        );
+        let digit = _mm256_cmpgt_epi8(
+            _mm256_set1_epi8(DIGIT_LIMIT),
+            _mm256_add_epi8(v, _mm256_set1_epi8(DIGIT_SHIFT)),
+        );
+        _mm256_movemask_epi8(_mm256_or_si256(letter, digit)) == -1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate_swar::validate_chunk_alnum;
+
+    const BASE: &[u8; 64] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZaz";
+
+    fn swar_is_alnum(bytes: &[u8]) -> bool {
+        bytes.chunks_exact(8).all(|chunk| {
+            let chunk = u64::from_le_bytes(chunk.try_into().unwrap());
+            validate_chunk_alnum(chunk).0
+        })
+    }
+
+    // Runs the SIMD and SWAR paths over every byte value at every position.
+    #[test]
+    fn test_matches_swar() {
+        for len in [16, 32, 48, 64] {
+            for pos in 0..len {
+                for byte in 0..=u8::MAX {
+                    let mut input = BASE[..len].to_vec();
+                    input[pos] = byte;
+                    assert_eq!(
+                        validate_blocks_alnum(&input).is_some(),
+                        swar_is_alnum(&input),
+                        "len {len}, byte {byte:#04x} at {pos}",
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_consumed_length() {
+        assert_eq!(validate_blocks_alnum(b""), Some(0));
+        assert_eq!(validate_blocks_alnum(&BASE[..15]), Some(0));
+        assert_eq!(validate_blocks_alnum(&BASE[..16]), Some(16));
+        assert_eq!(validate_blocks_alnum(&BASE[..47]), Some(32));
+        assert_eq!(validate_blocks_alnum(&BASE[..]), Some(64));
+    }
+
+    #[test]
+    fn test_ignores_tail() {
+        let mut input = BASE[..20].to_vec();
+        input[18] = b'-';
+        assert_eq!(validate_blocks_alnum(&input), Some(16));
+    }
+}
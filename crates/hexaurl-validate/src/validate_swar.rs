@@ -2,6 +2,7 @@ const PAIR_MASK: u64 = 0x000000FF000000FF;
 const BIAS: u64 = 0x8000000080000000;
 const BYTE_HIGH_BITS: u64 = 0x8080808080808080;
 const BYTE_ONES: u64 = 0x0101010101010101;
+const BYTE_LOW_BITS: u64 = 0x7F7F7F7F7F7F7F7F;
 
 #[inline(always)]
 fn validate_pair_alnum(pair: u64) -> u64 {
@@ -41,6 +42,49 @@ fn has_byte(chunk: u64, needle: u8) -> bool {
     ((x.wrapping_sub(BYTE_ONES)) & (!x) & BYTE_HIGH_BITS) != 0
 }
 
+/// Sets the high bit of each byte of `chunk` equal to `needle`, and no other bits.
+///
+/// Unlike [`has_byte`], no borrow crosses byte boundaries, so the mask is exact per byte.
+#[inline(always)]
+fn byte_eq_mask(chunk: u64, needle: u8) -> u64 {
+    let x = chunk ^ (u64::from(needle) * BYTE_ONES);
+    !(((x & BYTE_LOW_BITS).wrapping_add(BYTE_LOW_BITS)) | x) & BYTE_HIGH_BITS
+}
+
+/// Whether any two adjacent bytes of `chunk` are both `needle`.
+///
+/// Pairs spanning two chunks are not seen, so callers must still check chunk boundaries.
+#[inline(always)]
+fn has_adjacent_byte(chunk: u64, needle: u8) -> bool {
+    let eq = byte_eq_mask(chunk, needle);
+    eq & (eq >> 8) != 0
+}
+
+/// Whether `chunk` contains two consecutive hyphens.
+#[inline(always)]
+pub fn has_consecutive_hyphens(chunk: u64) -> bool {
+    has_adjacent_byte(chunk, b'-')
+}
+
+/// Whether `chunk` contains two consecutive underscores.
+#[inline(always)]
+pub fn has_consecutive_underscores(chunk: u64) -> bool {
+    has_adjacent_byte(chunk, b'_')
+}
+
+/// Lowercases the ASCII letters of `chunk`, leaving every other byte unchanged.
+///
+/// Each byte is tested against `'A'..='Z'` in its low seven bits; no carry crosses a
+/// byte boundary, so the letters can be flagged and shifted into bit 5 all at once.
+#[inline(always)]
+pub fn to_ascii_lowercase(chunk: u64) -> u64 {
+    let low = chunk & BYTE_LOW_BITS;
+    let ge_a = low.wrapping_add(BYTE_ONES * u64::from(0x80 - b'A'));
+    let gt_z = low.wrapping_add(BYTE_ONES * u64::from(0x7F - b'Z'));
+    let upper = ge_a & !gt_z & !chunk & BYTE_HIGH_BITS;
+    chunk | (upper >> 2)
+}
+
 #[inline(always)]
 fn split_pairs(chunk: u64) -> (u64, u64, u64, u64) {
     let pair1 = chunk & PAIR_MASK;
@@ -95,3 +139,63 @@ pub fn validate_chunk_both(chunk: u64) -> (bool, bool, bool) {
         has_byte(chunk, b'_'),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scalar_has_adjacent(bytes: &[u8; 8], needle: u8) -> bool {
+        bytes.windows(2).any(|pair| pair == [needle, needle])
+    }
+
+    // Runs every 8-byte string over a small alphabet, including bytes that differ from a
+    // delimiter only in the high bit, against a scalar scan.
+    #[test]
+    fn test_consecutive_delimiters_match_scalar() {
+        const ALPHABET: [u8; 5] = [b'a', b'-', b'_', b'-' | 0x80, 0];
+        for n in 0..ALPHABET.len().pow(8) {
+            let mut bytes = [0u8; 8];
+            let mut rest = n;
+            for byte in &mut bytes {
+                *byte = ALPHABET[rest % ALPHABET.len()];
+                rest /= ALPHABET.len();
+            }
+            let chunk = u64::from_le_bytes(bytes);
+            assert_eq!(
+                has_consecutive_hyphens(chunk),
+                scalar_has_adjacent(&bytes, b'-'),
+                "{bytes:?}",
+            );
+            assert_eq!(
+                has_consecutive_underscores(chunk),
+                scalar_has_adjacent(&bytes, b'_'),
+                "{bytes:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_ascii_lowercase() {
+        for b in 0..=u8::MAX {
+            let bytes = [b, b'A', b'z', b, b'-', b'_', b'9', b];
+            let expected = bytes.map(|b| b.to_ascii_lowercase());
+            assert_eq!(
+                to_ascii_lowercase(u64::from_ne_bytes(bytes)).to_ne_bytes(),
+                expected,
+                "{b:#04x}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_consecutive_delimiters() {
+        let chunk = |s: &[u8; 8]| u64::from_le_bytes(*s);
+        assert!(has_consecutive_hyphens(chunk(b"abc--def")));
+        assert!(has_consecutive_hyphens(chunk(b"--abcdef")));
+        assert!(has_consecutive_hyphens(chunk(b"abcdef--")));
+        assert!(!has_consecutive_hyphens(chunk(b"a-b-c-d-")));
+        assert!(!has_consecutive_hyphens(chunk(b"abc__def")));
+        assert!(has_consecutive_underscores(chunk(b"abc__def")));
+        assert!(!has_consecutive_underscores(chunk(b"a_-_b-_c")));
+    }
+}
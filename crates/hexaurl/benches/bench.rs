@@ -21,13 +21,17 @@ mod benches {
 
     use fixedstr::str32;
     use hexaurl::{
-        decode::{decode, decode_into, decode_unchecked, decode_unchecked_into},
-        encode::{encode, encode_quick, encode_unchecked},
+        decode::{
+            decode, decode_into, decode_unchecked, decode_unchecked_into, decode_with_config,
+        },
+        encode::{encode, encode_quick, encode_unchecked, encode_with_config},
+        struct_api::{HexaUrl128, HexaUrl16, HexaUrl256, HexaUrl32, HexaUrl64, HexaUrl8},
         HexaUrl,
     };
     use hexaurl_validate::{
         config::{Composition, Config, DelimiterRules},
-        validate, validate_for_lookup, validate_with_compiled_config, validate_with_config,
+        validate, validate_ascii_charset, validate_for_lookup, validate_with_compiled_config,
+        validate_with_config,
     };
     use once_cell::sync::Lazy;
     use std::collections::{BTreeMap, HashMap};
@@ -615,6 +619,16 @@ mod benches {
         b.iter(|| validate_for_lookup::<16>(black_box(MEDIUM_INPUT)));
     }
 
+    #[bench]
+    fn validate_ascii_charset_short(b: &mut Bencher) {
+        b.iter(|| validate_ascii_charset::<16>(black_box(SHORT_INPUT.as_bytes())));
+    }
+
+    #[bench]
+    fn validate_ascii_charset_medium(b: &mut Bencher) {
+        b.iter(|| validate_ascii_charset::<16>(black_box(MEDIUM_INPUT.as_bytes())));
+    }
+
     // Encoding benchmarks
     #[bench]
     fn encode_short(b: &mut Bencher) {
@@ -631,6 +645,31 @@ mod benches {
         b.iter(|| encode::<16>(black_box(LONG_INPUT)));
     }
 
+    // Two-pass baseline for `encode`, which fuses validation with packing.
+    #[bench]
+    fn encode_two_pass_short(b: &mut Bencher) {
+        b.iter(|| {
+            validate::<16>(black_box(SHORT_INPUT))
+                .map(|()| unsafe { encode_unchecked::<16>(black_box(SHORT_INPUT)) })
+        });
+    }
+
+    #[bench]
+    fn encode_two_pass_medium(b: &mut Bencher) {
+        b.iter(|| {
+            validate::<16>(black_box(MEDIUM_INPUT))
+                .map(|()| unsafe { encode_unchecked::<16>(black_box(MEDIUM_INPUT)) })
+        });
+    }
+
+    #[bench]
+    fn encode_two_pass_long(b: &mut Bencher) {
+        b.iter(|| {
+            validate::<16>(black_box(LONG_INPUT))
+                .map(|()| unsafe { encode_unchecked::<16>(black_box(LONG_INPUT)) })
+        });
+    }
+
     #[bench]
     fn encode_unchecked_short(b: &mut Bencher) {
         b.iter(|| unsafe { encode_unchecked::<16>(black_box(SHORT_INPUT)) });
@@ -743,6 +782,27 @@ mod benches {
         });
     }
 
+    // Rejected decoding benchmarks
+    //
+    // `decode_with_config` validates the stack buffer before allocating; the eager variant
+    // allocates the `String` first and validates it afterwards.
+    #[bench]
+    fn decode_rejected(b: &mut Bencher) {
+        let encoded = encode_quick::<16>(ERROR_CONSEC_HYPHEN).unwrap();
+        let config = Config::<16>::default();
+        b.iter(|| decode_with_config::<16, 21>(black_box(&encoded), &config));
+    }
+
+    #[bench]
+    fn decode_rejected_eager(b: &mut Bencher) {
+        let encoded = encode_quick::<16>(ERROR_CONSEC_HYPHEN).unwrap();
+        let config = Config::<16>::default();
+        b.iter(|| {
+            let decoded = decode_unchecked::<16, 21>(black_box(&encoded));
+            validate_with_config::<16>(&decoded, &config).map(|()| decoded)
+        });
+    }
+
     // Encoding safety benchmarks
     #[bench]
     fn encode_quick_short(b: &mut Bencher) {
@@ -758,4 +818,121 @@ mod benches {
     fn encode_quick_long(b: &mut Bencher) {
         b.iter(|| encode_quick::<16>(black_box(LONG_INPUT)));
     }
+
+    // Equality benchmarks
+    //
+    // Compares `PartialEq` on mostly-empty 256-byte values with a word-wise
+    // comparison that exits at the first differing `u64`.
+    fn words_eq(a: &[u8; 256], b: &[u8; 256]) -> bool {
+        a.chunks_exact(8).zip(b.chunks_exact(8)).all(|(x, y)| {
+            u64::from_ne_bytes(x.try_into().unwrap()) == u64::from_ne_bytes(y.try_into().unwrap())
+        })
+    }
+
+    #[bench]
+    fn eq_256_equal(b: &mut Bencher) {
+        let lhs = HexaUrl256::new(SHORT_INPUT).unwrap();
+        let rhs = HexaUrl256::new(SHORT_INPUT).unwrap();
+        b.iter(|| black_box(&lhs) == black_box(&rhs));
+    }
+
+    #[bench]
+    fn eq_256_differ(b: &mut Bencher) {
+        let lhs = HexaUrl256::new(SHORT_INPUT).unwrap();
+        let rhs = HexaUrl256::new(MEDIUM_INPUT).unwrap();
+        b.iter(|| black_box(&lhs) == black_box(&rhs));
+    }
+
+    #[bench]
+    fn eq_256_words_equal(b: &mut Bencher) {
+        let lhs = HexaUrl256::new(SHORT_INPUT).unwrap();
+        let rhs = HexaUrl256::new(SHORT_INPUT).unwrap();
+        b.iter(|| words_eq(black_box(lhs.as_bytes()), black_box(rhs.as_bytes())));
+    }
+
+    #[bench]
+    fn eq_256_words_differ(b: &mut Bencher) {
+        let lhs = HexaUrl256::new(SHORT_INPUT).unwrap();
+        let rhs = HexaUrl256::new(MEDIUM_INPUT).unwrap();
+        b.iter(|| words_eq(black_box(lhs.as_bytes()), black_box(rhs.as_bytes())));
+    }
+
+    // Size variant benchmarks
+    //
+    // Inputs fill 25%, 50%, 75% and 100% of each type's capacity. The minimal config is used
+    // so that short fills are not rejected by the default minimum length.
+    fn sized_input(len: usize) -> String {
+        "abcdefghijklmnopqrstuvwxyz"
+            .chars()
+            .cycle()
+            .take(len)
+            .collect()
+    }
+
+    macro_rules! fill_benches {
+        ($fill:ident, $ty:ty, $n:literal, $s:literal, $percent:literal) => {
+            mod $fill {
+                use super::super::*;
+
+                fn input() -> String {
+                    sized_input($s * $percent / 100)
+                }
+
+                #[bench]
+                fn encode(b: &mut Bencher) {
+                    let input = input();
+                    let config = Config::<$n>::minimal();
+                    b.iter(|| encode_with_config::<$n>(black_box(&input), &config));
+                }
+
+                #[bench]
+                fn encode_unchecked(b: &mut Bencher) {
+                    let input = input();
+                    b.iter(|| unsafe { super::super::encode_unchecked::<$n>(black_box(&input)) });
+                }
+
+                #[bench]
+                fn decode(b: &mut Bencher) {
+                    let config = Config::<$n>::minimal();
+                    let encoded = encode_with_config::<$n>(&input(), &config).unwrap();
+                    b.iter(|| decode_with_config::<$n, $s>(black_box(&encoded), &config));
+                }
+
+                #[bench]
+                fn decode_unchecked(b: &mut Bencher) {
+                    let config = Config::<$n>::minimal();
+                    let encoded = encode_with_config::<$n>(&input(), &config).unwrap();
+                    b.iter(|| super::super::decode_unchecked::<$n, $s>(black_box(&encoded)));
+                }
+
+                #[bench]
+                fn len(b: &mut Bencher) {
+                    let config = Config::<$n>::minimal();
+                    let encoded = encode_with_config::<$n>(&input(), &config).unwrap();
+                    b.iter(|| {
+                        let value = unsafe { <$ty>::from_slice(black_box(&encoded)) };
+                        black_box(value.len())
+                    });
+                }
+            }
+        };
+    }
+
+    macro_rules! size_benches {
+        ($size:ident, $ty:ty, $n:literal, $s:literal) => {
+            mod $size {
+                fill_benches!(fill_25, $ty, $n, $s, 25);
+                fill_benches!(fill_50, $ty, $n, $s, 50);
+                fill_benches!(fill_75, $ty, $n, $s, 75);
+                fill_benches!(fill_100, $ty, $n, $s, 100);
+            }
+        };
+    }
+
+    size_benches!(size_8, HexaUrl8, 8, 10);
+    size_benches!(size_16, HexaUrl16, 16, 21);
+    size_benches!(size_32, HexaUrl32, 32, 42);
+    size_benches!(size_64, HexaUrl64, 64, 85);
+    size_benches!(size_128, HexaUrl128, 128, 170);
+    size_benches!(size_256, HexaUrl256, 256, 341);
 }
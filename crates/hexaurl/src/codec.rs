@@ -0,0 +1,156 @@
+//! Versioned Codec
+//!
+//! This module provides [`HexaUrlCodec`], which prefixes the encoded bytes with a version
+//! header so stored values stay readable if the packing scheme changes in the future.
+//!
+//! The layout is one version byte followed by the packed string, zero-padded to `N`
+//! bytes. A codec value therefore holds strings up to the capacity of `N - 1` bytes.
+
+use crate::Error;
+use crate::{decode, encode};
+use hexaurl_validate::max_str_len;
+
+/// Explicit HexaURL codec writing a leading version byte.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::codec::HexaUrlCodec;
+///
+/// let bytes: [u8; 16] = HexaUrlCodec::encode_v1("hello").unwrap();
+/// assert_eq!(bytes[0], HexaUrlCodec::V1);
+/// assert_eq!(HexaUrlCodec::decode::<16, 21>(&bytes).unwrap(), "hello");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HexaUrlCodec {
+    version: u8,
+}
+
+impl HexaUrlCodec {
+    /// Version 1: the SIXBIT packing used by [`encode`](crate::encode()).
+    pub const V1: u8 = 1;
+
+    /// Creates a codec writing the given format version.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidByte`] if `version` is not a known format version.
+    #[inline]
+    pub const fn new(version: u8) -> Result<Self, Error> {
+        match version {
+            Self::V1 => Ok(Self { version }),
+            _ => Err(Error::InvalidByte),
+        }
+    }
+
+    /// Returns the format version this codec writes.
+    #[inline]
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Encodes the input string with this codec's format version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` under the same conditions as the versioned encoder, such as
+    /// [`encode_v1`](Self::encode_v1).
+    #[inline]
+    pub fn encode<const N: usize>(&self, input: &str) -> Result<[u8; N], Error> {
+        match self.version {
+            Self::V1 => Self::encode_v1(input),
+            _ => unreachable!("HexaUrlCodec is only constructed with known versions"),
+        }
+    }
+
+    /// Encodes the input string with format version 1 and the default validation rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::StringTooLong`] if the input does not fit into `N - 1` bytes, or an
+    /// `Error` under the same conditions as [`encode`](crate::encode()).
+    pub fn encode_v1<const N: usize>(input: &str) -> Result<[u8; N], Error> {
+        let max_len = max_str_len(N.saturating_sub(1));
+        if N == 0 || input.len() > max_len {
+            return Err(Error::StringTooLong(max_len));
+        }
+
+        let mut bytes = encode::<N>(input)?;
+        bytes.copy_within(..N - 1, 1);
+        bytes[0] = Self::V1;
+        Ok(bytes)
+    }
+
+    /// Reads the version byte and decodes the rest with the matching decoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidByte`] if the version byte is missing or unknown, or an
+    /// `Error` if the decoded string fails to validate.
+    pub fn decode<const N: usize, const S: usize>(bytes: &[u8; N]) -> Result<String, Error> {
+        match bytes.first() {
+            Some(&Self::V1) => {
+                let mut payload = [0; N];
+                payload[..N - 1].copy_from_slice(&bytes[1..]);
+                decode::<N, S>(&payload)
+            }
+            _ => Err(Error::InvalidByte),
+        }
+    }
+}
+
+impl Default for HexaUrlCodec {
+    /// Returns a codec writing the latest format version.
+    #[inline]
+    fn default() -> Self {
+        Self { version: Self::V1 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codec_round_trip() {
+        for input in ["abc", "hello", "hello-world", "abcdefghijklmnopqrst"] {
+            let bytes: [u8; 16] = HexaUrlCodec::encode_v1(input).unwrap();
+            assert_eq!(bytes[0], HexaUrlCodec::V1);
+            assert_eq!(&bytes[1..], &encode::<16>(input).unwrap()[..15]);
+            assert_eq!(HexaUrlCodec::decode::<16, 21>(&bytes).unwrap(), input);
+        }
+
+        let codec = HexaUrlCodec::default();
+        assert_eq!(codec, HexaUrlCodec::new(HexaUrlCodec::V1).unwrap());
+        assert_eq!(codec.version(), 1);
+        assert_eq!(
+            codec.encode::<16>("hello"),
+            HexaUrlCodec::encode_v1::<16>("hello")
+        );
+    }
+
+    #[test]
+    fn test_codec_errors() {
+        assert_eq!(
+            HexaUrlCodec::encode_v1::<16>(&"a".repeat(21)),
+            Err(Error::StringTooLong(20))
+        );
+        assert_eq!(
+            HexaUrlCodec::encode_v1::<0>(""),
+            Err(Error::StringTooLong(0))
+        );
+        assert_eq!(
+            HexaUrlCodec::encode_v1::<16>("-hello"),
+            Err(Error::LeadingTrailingHyphen)
+        );
+
+        let mut bytes: [u8; 16] = HexaUrlCodec::encode_v1("hello").unwrap();
+        bytes[0] = 2;
+        assert_eq!(
+            HexaUrlCodec::decode::<16, 21>(&bytes),
+            Err(Error::InvalidByte)
+        );
+        assert_eq!(HexaUrlCodec::decode::<0, 0>(&[]), Err(Error::InvalidByte));
+        assert_eq!(HexaUrlCodec::new(0), Err(Error::InvalidByte));
+    }
+}
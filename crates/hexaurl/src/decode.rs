@@ -4,8 +4,9 @@
 //! to ensure all HexaURL values are within the valid range, while the unchecked functions assume the input
 //! is already valid for increased performance.
 
+use crate::utils::len;
 use crate::{Error, MASK_FOUR_BITS, MASK_SIX_BITS, MASK_TWO_BITS};
-use hexaurl_validate::{config::Config, validate_with_config};
+use hexaurl_validate::{config::Config, min_byte_capacity, validate_with_config};
 use std::str;
 
 /// This function converts a slice of HexaURL-encoded bytes into the original string based on the provided length.
@@ -38,6 +39,9 @@ pub fn decode<const N: usize, const S: usize>(bytes: &[u8; N]) -> Result<String,
 /// # Returns
 /// A `Result` containing the decoded string if validation succeeds, or an `Error` otherwise.
 ///
+/// The string is decoded into a stack buffer and validated there, so nothing is allocated
+/// when validation fails.
+///
 /// # Errors
 /// Returns an `Error` if the decoded string fails to validate according to the provided configuration.
 #[inline]
@@ -50,6 +54,29 @@ pub fn decode_with_config<const N: usize, const S: usize>(
     Ok(res.to_owned())
 }
 
+/// Decodes HexaURL-encoded bytes into a `Box<str>` using the default validation configuration.
+///
+/// A `Box<str>` is one word smaller than a `String` and is allocated at the exact length,
+/// which adds up when storing many decoded identifiers.
+///
+/// # Errors
+/// Returns an `Error` under the same conditions as [`decode`].
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::{decode_boxed, encode};
+///
+/// let encoded_bytes: [u8; 16] = encode("hello").unwrap();
+/// let decoded: Box<str> = decode_boxed::<16, 21>(&encoded_bytes).unwrap();
+/// assert_eq!(&*decoded, "hello");
+/// ```
+#[inline]
+pub fn decode_boxed<const N: usize, const S: usize>(bytes: &[u8; N]) -> Result<Box<str>, Error> {
+    let mut dst = [0u8; S];
+    decode_into::<N, S>(bytes, &mut dst).map(Box::from)
+}
+
 /// Decodes into a caller-provided buffer using default validation configuration.
 ///
 /// Returns a borrowed string slice into `dst`, avoiding allocation in the decode path.
@@ -78,6 +105,213 @@ pub fn decode_into_with_config<'a, const N: usize, const S: usize>(
     Ok(res)
 }
 
+/// Checks that HexaURL-encoded bytes decode to a string accepted by `config`, without
+/// allocating or returning the string.
+///
+/// This is the validation counterpart of the struct API's `try_from_bytes`.
+///
+/// # Errors
+/// Returns [`Error::BytesTooLong`] with the byte limit if the bytes before the zero padding
+/// cannot hold a string within `config`'s maximum length. Otherwise returns an `Error` if
+/// the decoded string fails to validate according to `config`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::{config::Config, encode, encode_quick, validate_bytes, Error};
+///
+/// let config = Config::<16>::default();
+/// let valid: [u8; 16] = encode("hello").unwrap();
+/// assert_eq!(validate_bytes::<16, 21>(&valid, &config), Ok(()));
+///
+/// let invalid: [u8; 16] = encode_quick("-hello").unwrap();
+/// assert_eq!(
+///     validate_bytes::<16, 21>(&invalid, &config),
+///     Err(Error::LeadingTrailingHyphen)
+/// );
+/// ```
+#[inline]
+pub fn validate_bytes<const N: usize, const S: usize>(
+    bytes: &[u8; N],
+    config: &Config<N>,
+) -> Result<(), Error> {
+    let max_bytes = min_byte_capacity(config.effective_max());
+    if len(bytes) > max_bytes {
+        return Err(Error::BytesTooLong(max_bytes));
+    }
+    let mut dst = [0u8; S];
+    decode_into_with_config::<N, S>(bytes, &mut dst, config)?;
+    Ok(())
+}
+
+/// Decodes each of `items` with the default validation configuration and pushes the
+/// results into `out`.
+///
+/// `out` is cleared first, so a single `Vec` can be reused across batches, e.g. pages of
+/// stored keys. The configuration and the stack scratch buffer are shared by all items.
+///
+/// # Errors
+/// Returns an `Error` for the first item that fails to validate. `out` then holds the
+/// items decoded before it.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::{encode, decode_batch};
+///
+/// let items: [[u8; 16]; 2] = [encode("alpha").unwrap(), encode("beta").unwrap()];
+/// let mut out = Vec::new();
+/// decode_batch::<16, 21>(&items, &mut out).unwrap();
+/// assert_eq!(out, ["alpha", "beta"]);
+/// ```
+#[inline]
+pub fn decode_batch<const N: usize, const S: usize>(
+    items: &[[u8; N]],
+    out: &mut Vec<String>,
+) -> Result<(), Error> {
+    let config = Config::<N>::default();
+    let mut dst = [0u8; S];
+    out.clear();
+    out.reserve(items.len());
+    for bytes in items {
+        let res = decode_into_with_config::<N, S>(bytes, &mut dst, &config)?;
+        out.push(res.to_owned());
+    }
+    Ok(())
+}
+
+/// Incremental decoder for HexaURL bytes that arrive in pieces, e.g. from a non-blocking
+/// socket.
+///
+/// Bytes are buffered with [`push`](Self::push) into a `[u8; N]`, and
+/// [`finish`](Self::finish) decodes them through the same path as [`decode`]. Bytes that
+/// were never pushed are treated as zero padding, so a sender may stop after the
+/// significant bytes of a short value.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::{encode, DecodeState};
+///
+/// let encoded: [u8; 16] = encode("hello").unwrap();
+/// let mut state = DecodeState::<16, 21>::new();
+/// for chunk in encoded.chunks(5) {
+///     chunk.iter().for_each(|&byte| state.push(byte));
+/// }
+/// assert_eq!(state.finish().unwrap(), "hello");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeState<const N: usize, const S: usize> {
+    buf: [u8; N],
+    len: usize,
+    overflowed: bool,
+}
+
+impl<const N: usize, const S: usize> DecodeState<N, S> {
+    /// Creates an empty decoder.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+            overflowed: false,
+        }
+    }
+
+    /// Returns the number of bytes buffered so far.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no bytes have been buffered.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` once all `N` bytes have been buffered.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Buffers one byte.
+    ///
+    /// Bytes beyond the first `N` are dropped, and [`finish`](Self::finish) then fails with
+    /// [`Error::BytesTooLong`].
+    #[inline]
+    pub fn push(&mut self, byte: u8) {
+        if self.len < N {
+            self.buf[self.len] = byte;
+            self.len += 1;
+        } else {
+            self.overflowed = true;
+        }
+    }
+
+    /// Decodes the buffered bytes with the default validation configuration.
+    ///
+    /// # Errors
+    /// Returns [`Error::BytesTooLong`] if more than `N` bytes were pushed, or an `Error` if
+    /// the decoded string fails to validate.
+    #[inline]
+    pub fn finish(self) -> Result<String, Error> {
+        let config = Config::<N>::default();
+        self.finish_with_config(&config)
+    }
+
+    /// Decodes the buffered bytes using a custom validation configuration.
+    ///
+    /// # Errors
+    /// Returns [`Error::BytesTooLong`] if more than `N` bytes were pushed, or an `Error` if
+    /// the decoded string fails to validate according to `config`.
+    #[inline]
+    pub fn finish_with_config(self, config: &Config<N>) -> Result<String, Error> {
+        if self.overflowed {
+            return Err(Error::BytesTooLong(N));
+        }
+        decode_with_config::<N, S>(&self.buf, config)
+    }
+}
+
+impl<const N: usize, const S: usize> Default for DecodeState<N, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Decodes HexaURL-encoded bytes with only a quick integrity check.
+///
+/// This is the decoding counterpart of [`encode_quick`](crate::encode_quick): it skips
+/// length, composition and delimiter rules, but rejects payloads containing SIXBIT
+/// units that do not map to a HexaURL character, so the result is always a faithful
+/// decoding of `bytes`.
+///
+/// # Errors
+/// Returns [`Error::InvalidByte`] if `bytes` contains a non-HexaURL SIXBIT unit before
+/// the end of the string.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::{encode, decode_quick};
+///
+/// let encoded_bytes: [u8; 16] = encode("hello").unwrap();
+/// assert_eq!(decode_quick::<16, 21>(&encoded_bytes).unwrap(), "hello");
+/// ```
+#[inline]
+pub fn decode_quick<const N: usize, const S: usize>(bytes: &[u8; N]) -> Result<String, Error> {
+    let mut dst = [0u8; S];
+    let res = decode_core::<N, S>(bytes, &mut dst);
+    if res.contains(&0) {
+        return Err(Error::InvalidByte);
+    }
+    // SAFETY: decode_core only emits ASCII bytes from the lookup table, which are always valid UTF-8.
+    Ok(unsafe { str::from_utf8_unchecked(res) }.to_owned())
+}
+
 /// This function performs decoding without running HexaURL validation checks.
 /// It is faster than [`decode`] and [`decode_with_config`], but accepts any byte pattern.
 ///
@@ -152,6 +386,44 @@ const unsafe fn convert(byte: u8) -> u8 {
     unsafe { LOOKUP_TABLE.as_ptr().add(byte as usize).read() }
 }
 
+/// Maps a single 6-bit unit to its decoded character, or `None` if the unit is not part of
+/// the HexaURL alphabet.
+#[cfg(feature = "struct-api")]
+#[inline(always)]
+pub(crate) const fn decode_unit(unit: u8) -> Option<char> {
+    match LOOKUP_TABLE[(unit & MASK_SIX_BITS) as usize] {
+        0 => None,
+        c => Some(c as char),
+    }
+}
+
+/// Returns the lowercase ASCII character for a SIXBIT code, or `None` if the code is not
+/// part of the HexaURL alphabet or does not fit in six bits.
+///
+/// This is the inverse of [`char_to_sixbit`](crate::encode::char_to_sixbit) for lowercase
+/// characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::decode::sixbit_to_char;
+///
+/// assert_eq!(sixbit_to_char(33), Some(b'a'));
+/// assert_eq!(sixbit_to_char(63), Some(b'_'));
+/// assert_eq!(sixbit_to_char(0), None);
+/// assert_eq!(sixbit_to_char(64), None);
+/// ```
+#[inline(always)]
+pub const fn sixbit_to_char(code: u8) -> Option<u8> {
+    if code > MASK_SIX_BITS {
+        return None;
+    }
+    match LOOKUP_TABLE[code as usize] {
+        0 => None,
+        c => Some(c),
+    }
+}
+
 /// Calculates the number of full 3-byte chunks in the input.
 #[inline(always)]
 const fn full_chunks(n: usize) -> usize {
@@ -243,7 +515,7 @@ pub(crate) fn decode_core<'a, const N: usize, const S: usize>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::encode::encode;
+    use crate::encode::{encode, encode_quick};
 
     #[test]
     fn test_encode_and_decode() {
@@ -263,6 +535,104 @@ mod tests {
         assert_eq!(original.to_ascii_lowercase(), decoded);
     }
 
+    #[test]
+    fn test_decode_boxed() {
+        for original in ["abc", "Hello-World", "a".repeat(21).as_str()] {
+            let encoded: [u8; 16] = encode(original).expect("Encoding failed");
+            let boxed = decode_boxed::<16, 21>(&encoded).expect("Boxed decoding failed");
+            assert_eq!(&*boxed, decode::<16, 21>(&encoded).unwrap());
+        }
+
+        let invalid: [u8; 16] = encode_quick("-abc").expect("Encoding failed");
+        assert_eq!(
+            decode_boxed::<16, 21>(&invalid),
+            Err(Error::LeadingTrailingHyphen)
+        );
+    }
+
+    #[test]
+    fn test_decode_quick() {
+        let original = "Quick_Test--";
+        let encoded: [u8; 16] = encode_quick(original).expect("Encoding failed");
+        let decoded = decode_quick::<16, 21>(&encoded).expect("Quick decoding failed");
+        assert_eq!(original.to_ascii_lowercase(), decoded);
+
+        // SIXBIT units 'a' (33), invalid (1), 'b' (34).
+        let mut invalid = [0u8; 16];
+        invalid[..3].copy_from_slice(&[0b1000_0100, 0b0001_1000, 0b1000_0000]);
+        assert_eq!(decode_quick::<16, 21>(&invalid), Err(Error::InvalidByte));
+    }
+
+    #[test]
+    fn test_decode_batch() {
+        let items: [[u8; 16]; 3] = [
+            encode("first").unwrap(),
+            encode("Second-Key").unwrap(),
+            encode("third-key").unwrap(),
+        ];
+        let mut out = vec!["stale".to_owned()];
+        decode_batch::<16, 21>(&items, &mut out).expect("Batch decoding failed");
+        let expected: Vec<String> = items
+            .iter()
+            .map(|item| decode::<16, 21>(item).unwrap())
+            .collect();
+        assert_eq!(out, expected);
+
+        let invalid = [items[0], encode_quick("-x").unwrap()];
+        assert!(decode_batch::<16, 21>(&invalid, &mut out).is_err());
+        assert_eq!(out, ["first"]);
+    }
+
+    #[test]
+    fn test_validate_bytes() {
+        let config = Config::<16>::default();
+        let valid: [u8; 16] = encode("Valid-Key").unwrap();
+        assert_eq!(validate_bytes::<16, 21>(&valid, &config), Ok(()));
+
+        let invalid: [u8; 16] = encode_quick("bad--key").unwrap();
+        assert_eq!(
+            validate_bytes::<16, 21>(&invalid, &config),
+            Err(Error::ConsecutiveHyphens)
+        );
+        let underscore: [u8; 16] = encode_quick("bad_key").unwrap();
+        assert_eq!(
+            validate_bytes::<16, 21>(&underscore, &config),
+            Err(Error::InvalidCharacter)
+        );
+
+        let short = Config::<16>::builder().max_length(Some(4)).build().unwrap();
+        let long: [u8; 16] = encode("abcdef").unwrap();
+        assert_eq!(
+            validate_bytes::<16, 21>(&long, &short),
+            Err(Error::BytesTooLong(3))
+        );
+        let fits: [u8; 16] = encode("abcd").unwrap();
+        assert_eq!(validate_bytes::<16, 21>(&fits, &short), Ok(()));
+    }
+
+    #[test]
+    fn test_decode_state() {
+        let encoded: [u8; 16] = encode("Stream-Key").unwrap();
+        let mut state = DecodeState::<16, 21>::new();
+        assert!(state.is_empty());
+        for byte in encoded {
+            state.push(byte);
+        }
+        assert!(state.is_full());
+        assert_eq!(state.finish(), decode::<16, 21>(&encoded));
+
+        // A short value needs only its significant bytes.
+        let encoded: [u8; 16] = encode("abcd").unwrap();
+        let mut state = DecodeState::<16, 21>::default();
+        encoded[..3].iter().for_each(|&byte| state.push(byte));
+        assert_eq!(state.len(), 3);
+        assert_eq!(state.finish().unwrap(), "abcd");
+
+        let mut state = DecodeState::<16, 21>::new();
+        (0..17).for_each(|_| state.push(0));
+        assert_eq!(state.finish(), Err(Error::BytesTooLong(16)));
+    }
+
     #[test]
     fn test_decode_unchecked() {
         let original = "Unchecked-Test";
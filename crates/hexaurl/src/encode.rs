@@ -6,19 +6,20 @@
 
 use crate::{Error, MASK_FOUR_BITS, MASK_TWO_BITS};
 use hexaurl_config::{Composition, Config};
-use hexaurl_validate::check_encoding_safe;
-
-/// Calculates the maximum length of the input string based on the number of output bytes.
-#[inline(always)]
-const fn calc_str_len(n: usize) -> usize {
-    n * 4 / 3
-}
+use hexaurl_validate::{
+    check_encoding_safe, max_str_len, trim_whitespace, unify_delimiters, validate_distinct_chars,
+    validate_reserved, validate_segments,
+};
 
 /// Encodes the input string into a compact HexaURL representation using default validation rules.
 ///
 /// This function validates that all characters in the string are within the allowed SIXBIT range and then encodes the string.
 /// It returns a fixed-size byte array containing the encoded result.
 ///
+/// Validation is fused with packing: each byte is class-checked, tracked for delimiter runs and packed in a
+/// single pass, failing on the first invalid character. Only the leading/trailing delimiter check and any
+/// segment, distinct-character or reserved-name rules run afterwards on the already-checked input.
+///
 /// # Arguments
 ///
 /// * `input` - A string slice that holds the data to be encoded.
@@ -61,7 +62,76 @@ pub fn encode_with_config<const N: usize>(
     input: &str,
     config: &Config<N>,
 ) -> Result<[u8; N], Error> {
-    encode_core_validated_with_config::<N>(input, config)
+    let input = unify_delimiters(trim_whitespace(input, config), config.unified_delimiter());
+    encode_core_validated_with_config::<N>(&input, config)
+}
+
+/// Minimum number of inputs each rayon task encodes in [`par_encode_batch`], so that small
+/// batches are not split into tasks cheaper than their scheduling.
+#[cfg(feature = "rayon")]
+const PAR_MIN_LEN: usize = 1024;
+
+/// Encodes each of `inputs` with the default validation configuration on the rayon thread
+/// pool.
+///
+/// The results are in input order, and each equals [`encode`] of the same input.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::{encode, par_encode_batch};
+///
+/// let encoded = par_encode_batch::<16>(&["alpha", "b"]);
+/// assert_eq!(encoded[0], encode::<16>("alpha"));
+/// assert!(encoded[1].is_err());
+/// ```
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub fn par_encode_batch<const N: usize>(inputs: &[&str]) -> Vec<Result<[u8; N], Error>> {
+    use rayon::prelude::*;
+
+    let config = Config::<N>::default();
+    inputs
+        .par_iter()
+        .with_min_len(PAR_MIN_LEN)
+        .map(|input| encode_with_config::<N>(input, &config))
+        .collect()
+}
+
+/// Byte capacity [`encode_small`] validates and encodes against, that of the largest
+/// HexaURL alias.
+#[cfg(feature = "smallvec")]
+const SMALL_MAX_BYTES: usize = 256;
+
+/// Encodes the input string with the default validation rules into a vector of exactly
+/// as many bytes as it needs, without choosing a byte capacity `N`.
+///
+/// The bytes are stored inline for strings of up to 21 characters, the capacity of a
+/// 16-byte HexaURL, and spill to the heap for longer ones. Padding the
+/// result with zeros to `N` bytes gives [`encode::<N>`](encode) of the same input.
+///
+/// # Errors
+///
+/// Returns an `Error` if validation fails. Strings longer than 341 characters, the capacity
+/// of a 256-byte HexaURL, are rejected with [`Error::StringTooLong`].
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::{encode, encode_small};
+///
+/// let small = encode_small("hello").unwrap();
+/// assert_eq!(small.len(), 4);
+/// assert!(!small.spilled());
+/// assert_eq!(small[..], encode::<16>("hello").unwrap()[..4]);
+/// ```
+#[cfg(feature = "smallvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+pub fn encode_small(input: &str) -> Result<smallvec::SmallVec<[u8; 16]>, Error> {
+    let bytes = encode::<SMALL_MAX_BYTES>(input)?;
+    Ok(smallvec::SmallVec::from_slice(
+        &bytes[..hexaurl_validate::min_byte_capacity(input.len())],
+    ))
 }
 
 /// Encodes the input string into a compact HexaURL representation using minimal validation rules.
@@ -94,6 +164,11 @@ pub fn encode_quick<const N: usize>(input: &str) -> Result<[u8; N], Error> {
 ///
 /// <div class="warning">The input string must be ASCII. Otherwise, it causes undefined behavior.</div>
 ///
+/// # Panics
+///
+/// The input must be at most `N * 4 / 3` bytes long. Longer input panics: debug builds
+/// report the length through an assertion, and release builds hit an out-of-bounds index.
+///
 /// # Arguments
 ///
 /// * `input` - A string slice that is assumed to be valid for HexaURL encoding.
@@ -117,6 +192,25 @@ pub unsafe fn encode_unchecked<const N: usize>(input: &str) -> [u8; N] {
     unsafe { encode_core(input) }
 }
 
+/// Returns `true` if encoding `input` would lose information through case folding.
+///
+/// Encoding folds letters to lowercase, so any uppercase ASCII letter in `input` means the
+/// decoded string will not reproduce it exactly. Only the case is checked; the input is not
+/// validated.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::encode_is_lossy;
+///
+/// assert!(!encode_is_lossy("foo"));
+/// assert!(encode_is_lossy("Foo"));
+/// ```
+#[inline]
+pub fn encode_is_lossy(input: &str) -> bool {
+    input.bytes().any(|b| b.is_ascii_uppercase())
+}
+
 // ============================================================
 //
 //            HexaURL Core Encoding Logic
@@ -156,33 +250,46 @@ const unsafe fn convert(byte: u8) -> u8 {
     unsafe { LOOKUP_TABLE.as_ptr().add(byte as usize).read() }
 }
 
+/// Returns the SIXBIT code of an ASCII character, or `None` if it is not part of the
+/// HexaURL alphabet. Uppercase and lowercase letters map to the same code.
+///
+/// This is the inverse of [`sixbit_to_char`](crate::decode::sixbit_to_char).
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::encode::char_to_sixbit;
+///
+/// assert_eq!(char_to_sixbit(b'a'), Some(33));
+/// assert_eq!(char_to_sixbit(b'A'), Some(33));
+/// assert_eq!(char_to_sixbit(b'-'), Some(13));
+/// assert_eq!(char_to_sixbit(b' '), None);
+/// ```
 #[inline(always)]
-fn sixbit_value(byte: u8) -> Option<u8> {
-    if byte >= 128 {
+pub const fn char_to_sixbit(c: u8) -> Option<u8> {
+    if c >= 128 {
         return None;
     }
-    let val = LOOKUP_TABLE[byte as usize];
-    if val == 0 {
-        None
-    } else {
-        Some(val)
+    match LOOKUP_TABLE[c as usize] {
+        0 => None,
+        code => Some(code),
     }
 }
 
 #[inline(always)]
 fn encode_core_minimal_validated<const N: usize>(input: &str) -> Result<[u8; N], Error> {
-    if input.len() > calc_str_len(N) {
-        return Err(Error::StringTooLong(calc_str_len(N)));
+    if input.len() > max_str_len(N) {
+        return Err(Error::StringTooLong(max_str_len(N)));
     }
 
     encode_core_validated_inner::<N>(
         input.as_bytes(),
         true,
         true,
-        hexaurl_config::DelimiterRules::default(),
+        &hexaurl_config::DelimiterRules::default(),
         None,
-        false,
-        false,
+        Some(1),
+        Some(1),
     )
 }
 
@@ -203,19 +310,29 @@ fn encode_core_validated_with_config<const N: usize>(
         return Err(Error::StringTooLong(config.effective_max()));
     }
 
-    let delimiter_rules = config.delimiter_rules();
+    let delimiter_rules = config.delimiter_rules_ref();
     let allow_hyphen = config.allow_hyphen();
     let allow_underscore = config.allow_underscore();
 
-    encode_core_validated_inner::<N>(
+    let bytes = encode_core_validated_inner::<N>(
         input.as_bytes(),
         allow_hyphen,
         allow_underscore,
         delimiter_rules,
         Some(config.composition()),
-        delimiter_rules.allow_consecutive_hyphens(),
-        delimiter_rules.allow_consecutive_underscores(),
-    )
+        delimiter_rules.max_hyphen_run(),
+        delimiter_rules.max_underscore_run(),
+    )?;
+
+    if let Some(rule) = config.segment_rule() {
+        validate_segments(input, &rule)?;
+    }
+    if let Some(min) = config.min_distinct_chars() {
+        validate_distinct_chars(input, min)?;
+    }
+    validate_reserved(input, config.reserved())?;
+
+    Ok(bytes)
 }
 
 #[inline(always)]
@@ -223,10 +340,10 @@ fn encode_core_validated_inner<const N: usize>(
     input: &[u8],
     allow_hyphen: bool,
     allow_underscore: bool,
-    delimiter_rules: hexaurl_config::DelimiterRules,
+    delimiter_rules: &hexaurl_config::DelimiterRules,
     composition: Option<Composition>,
-    allow_consecutive_hyphens: bool,
-    allow_consecutive_underscores: bool,
+    max_hyphen_run: Option<usize>,
+    max_underscore_run: Option<usize>,
 ) -> Result<[u8; N], Error> {
     let len = input.len();
     let mut bytes = [0u8; N];
@@ -235,6 +352,7 @@ fn encode_core_validated_inner<const N: usize>(
     let mut last_byte: u8 = 0;
     let mut pending_delim_error: Option<Error> = None;
     let mut last_delim: Option<u8> = None;
+    let mut delim_run = 0usize;
 
     let full_chunks = len / 4;
     let remaining = len % 4;
@@ -258,8 +376,8 @@ fn encode_core_validated_inner<const N: usize>(
                 return Err(Error::InvalidCharacter);
             }
 
-            let Some(v) = sixbit_value(b) else {
-                return Err(Error::InvalidCharacter);
+            let Some(v) = char_to_sixbit(b) else {
+                return Err(Error::invalid_character(b));
             };
             vals[i] = v;
 
@@ -269,7 +387,12 @@ fn encode_core_validated_inner<const N: usize>(
                         Composition::Alphanumeric => {}
                         Composition::AlphanumericHyphen => {
                             if b == b'-' {
-                                if last_delim == Some(b'-') && !allow_consecutive_hyphens {
+                                delim_run = if last_delim == Some(b'-') {
+                                    delim_run + 1
+                                } else {
+                                    1
+                                };
+                                if max_hyphen_run.is_some_and(|max| delim_run > max) {
                                     pending_delim_error = Some(Error::ConsecutiveHyphens);
                                 }
                                 last_delim = Some(b'-');
@@ -279,7 +402,12 @@ fn encode_core_validated_inner<const N: usize>(
                         }
                         Composition::AlphanumericUnderscore => {
                             if b == b'_' {
-                                if last_delim == Some(b'_') && !allow_consecutive_underscores {
+                                delim_run = if last_delim == Some(b'_') {
+                                    delim_run + 1
+                                } else {
+                                    1
+                                };
+                                if max_underscore_run.is_some_and(|max| delim_run > max) {
                                     pending_delim_error = Some(Error::ConsecutiveUnderscores);
                                 }
                                 last_delim = Some(b'_');
@@ -289,18 +417,25 @@ fn encode_core_validated_inner<const N: usize>(
                         }
                         Composition::AlphanumericHyphenUnderscore => match b {
                             b'-' | b'_' => {
-                                if let Some(prev) = last_delim {
-                                    if prev == b {
-                                        if b == b'-' && !allow_consecutive_hyphens {
-                                            pending_delim_error = Some(Error::ConsecutiveHyphens);
-                                        }
-                                        if b == b'_' && !allow_consecutive_underscores {
-                                            pending_delim_error =
-                                                Some(Error::ConsecutiveUnderscores);
-                                        }
-                                    } else if !delimiter_rules.allow_adjacent_hyphen_underscore() {
+                                if last_delim == Some(b) {
+                                    delim_run += 1;
+                                    if b == b'-'
+                                        && max_hyphen_run.is_some_and(|max| delim_run > max)
+                                    {
+                                        pending_delim_error = Some(Error::ConsecutiveHyphens);
+                                    }
+                                    if b == b'_'
+                                        && max_underscore_run.is_some_and(|max| delim_run > max)
+                                    {
+                                        pending_delim_error = Some(Error::ConsecutiveUnderscores);
+                                    }
+                                } else {
+                                    if last_delim.is_some()
+                                        && !delimiter_rules.allow_adjacent_hyphen_underscore()
+                                    {
                                         pending_delim_error = Some(Error::AdjacentHyphenUnderscore);
                                     }
+                                    delim_run = 1;
                                 }
                                 last_delim = Some(b);
                             }
@@ -358,8 +493,8 @@ fn encode_core_validated_inner<const N: usize>(
                 return Err(Error::InvalidCharacter);
             }
 
-            let Some(v) = sixbit_value(b) else {
-                return Err(Error::InvalidCharacter);
+            let Some(v) = char_to_sixbit(b) else {
+                return Err(Error::invalid_character(b));
             };
             vals[i] = v;
 
@@ -369,7 +504,12 @@ fn encode_core_validated_inner<const N: usize>(
                         Composition::Alphanumeric => {}
                         Composition::AlphanumericHyphen => {
                             if b == b'-' {
-                                if last_delim == Some(b'-') && !allow_consecutive_hyphens {
+                                delim_run = if last_delim == Some(b'-') {
+                                    delim_run + 1
+                                } else {
+                                    1
+                                };
+                                if max_hyphen_run.is_some_and(|max| delim_run > max) {
                                     pending_delim_error = Some(Error::ConsecutiveHyphens);
                                 }
                                 last_delim = Some(b'-');
@@ -379,7 +519,12 @@ fn encode_core_validated_inner<const N: usize>(
                         }
                         Composition::AlphanumericUnderscore => {
                             if b == b'_' {
-                                if last_delim == Some(b'_') && !allow_consecutive_underscores {
+                                delim_run = if last_delim == Some(b'_') {
+                                    delim_run + 1
+                                } else {
+                                    1
+                                };
+                                if max_underscore_run.is_some_and(|max| delim_run > max) {
                                     pending_delim_error = Some(Error::ConsecutiveUnderscores);
                                 }
                                 last_delim = Some(b'_');
@@ -389,18 +534,25 @@ fn encode_core_validated_inner<const N: usize>(
                         }
                         Composition::AlphanumericHyphenUnderscore => match b {
                             b'-' | b'_' => {
-                                if let Some(prev) = last_delim {
-                                    if prev == b {
-                                        if b == b'-' && !allow_consecutive_hyphens {
-                                            pending_delim_error = Some(Error::ConsecutiveHyphens);
-                                        }
-                                        if b == b'_' && !allow_consecutive_underscores {
-                                            pending_delim_error =
-                                                Some(Error::ConsecutiveUnderscores);
-                                        }
-                                    } else if !delimiter_rules.allow_adjacent_hyphen_underscore() {
+                                if last_delim == Some(b) {
+                                    delim_run += 1;
+                                    if b == b'-'
+                                        && max_hyphen_run.is_some_and(|max| delim_run > max)
+                                    {
+                                        pending_delim_error = Some(Error::ConsecutiveHyphens);
+                                    }
+                                    if b == b'_'
+                                        && max_underscore_run.is_some_and(|max| delim_run > max)
+                                    {
+                                        pending_delim_error = Some(Error::ConsecutiveUnderscores);
+                                    }
+                                } else {
+                                    if last_delim.is_some()
+                                        && !delimiter_rules.allow_adjacent_hyphen_underscore()
+                                    {
                                         pending_delim_error = Some(Error::AdjacentHyphenUnderscore);
                                     }
+                                    delim_run = 1;
                                 }
                                 last_delim = Some(b);
                             }
@@ -486,6 +638,11 @@ fn encode_core_validated_inner<const N: usize>(
 ///
 /// The input string must be valid ASCII.
 ///
+/// # Panics
+///
+/// `input.len()` must not exceed `max_str_len(N)`. The validated callers guarantee this;
+/// for the unchecked ones it is a caller precondition, checked here in debug builds.
+///
 /// # Arguments
 ///
 /// * `input` - The string slice to encode.
@@ -496,6 +653,11 @@ fn encode_core_validated_inner<const N: usize>(
 #[inline(always)]
 unsafe fn encode_core<const N: usize>(input: &str) -> [u8; N] {
     let len = input.len();
+    debug_assert!(
+        len <= max_str_len(N),
+        "input length {len} exceeds the {} characters a [u8; {N}] can hold",
+        max_str_len(N)
+    );
     let mut bytes = [0u8; N];
 
     let full_chunks = len / 4;
@@ -561,6 +723,44 @@ unsafe fn encode_core<const N: usize>(input: &str) -> [u8; N] {
     bytes
 }
 
+/// Encodes `input` in a `const` context, one SIXBIT unit at a time.
+///
+/// Characters outside the HexaURL alphabet are encoded as zero units, so the caller
+/// decides whether to reject them first.
+///
+/// # Panics
+///
+/// Panics if `input` is longer than `max_str_len(N)`; in a `const` item this is a compile
+/// error.
+#[cfg(feature = "struct-api")]
+pub(crate) const fn encode_const<const N: usize>(input: &[u8]) -> [u8; N] {
+    assert!(
+        input.len() <= max_str_len(N),
+        "input is too long for this type of HexaURL"
+    );
+    let mut bytes = [0u8; N];
+    let mut i = 0;
+    while i < input.len() {
+        let unit = match char_to_sixbit(input[i]) {
+            Some(unit) => unit,
+            None => 0,
+        };
+        let bit = i * 6;
+        let byte = bit / 8;
+        let shift = bit % 8;
+        if shift <= 2 {
+            bytes[byte] |= unit << (2 - shift);
+        } else {
+            // The unit straddles two bytes; `input.len() <= max_str_len(N)` keeps the second
+            // one in bounds.
+            bytes[byte] |= unit >> (shift - 2);
+            bytes[byte + 1] |= ((unit as u16) << (10 - shift)) as u8;
+        }
+        i += 1;
+    }
+    bytes
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -582,6 +782,91 @@ mod tests {
         assert_eq!(encoded.len(), 16);
     }
 
+    #[test]
+    #[cfg(feature = "struct-api")]
+    fn test_encode_const() {
+        let input = "a1-b2_c3-d4e5-f6_g7h8";
+        for len in 0..=input.len() {
+            let expected: [u8; 16] = unsafe { encode_unchecked(&input[..len]) };
+            assert_eq!(encode_const::<16>(&input.as_bytes()[..len]), expected);
+        }
+        assert_eq!(encode_const::<8>(b"HELLO"), encode::<8>("hello").unwrap());
+    }
+
+    #[test]
+    fn test_char_to_sixbit() {
+        const ALPHABET: &[u8] = b"-0123456789abcdefghijklmnopqrstuvwxyz_";
+        for &c in ALPHABET {
+            let code = char_to_sixbit(c).unwrap();
+            assert_eq!(code, c.to_ascii_uppercase() - 32);
+            assert_eq!(char_to_sixbit(c.to_ascii_uppercase()), Some(code));
+            assert_eq!(crate::decode::sixbit_to_char(code), Some(c));
+        }
+
+        let valid = (0..=255u8).filter(|&c| char_to_sixbit(c).is_some()).count();
+        assert_eq!(valid, ALPHABET.len() + 26);
+        for c in [b' ', b'.', b'/', b'@', b'`', b'~', 0, 0x80, 0xFF] {
+            assert_eq!(char_to_sixbit(c), None);
+        }
+
+        let codes = (0..=255u8)
+            .filter(|&code| crate::decode::sixbit_to_char(code).is_some())
+            .count();
+        assert_eq!(codes, ALPHABET.len());
+        for code in [0, 1, 12, 14, 26, 32, 59, 62, 64, 97, 255] {
+            assert_eq!(crate::decode::sixbit_to_char(code), None);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_encode_batch() {
+        let inputs: Vec<String> = (0..5000)
+            .map(|i| match i % 4 {
+                0 => format!("user-{i}"),
+                1 => format!("x{i}"),
+                2 => format!("-{i}"),
+                _ => "a".repeat(i % 30),
+            })
+            .collect();
+        let inputs: Vec<&str> = inputs.iter().map(String::as_str).collect();
+
+        let parallel = par_encode_batch::<16>(&inputs);
+        assert_eq!(parallel.len(), inputs.len());
+        for (input, result) in inputs.iter().zip(&parallel) {
+            assert_eq!(*result, encode::<16>(input));
+        }
+        assert!(parallel.iter().any(Result::is_ok));
+        assert!(parallel.iter().any(Result::is_err));
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn test_encode_small() {
+        let short = encode_small("user-alice").unwrap();
+        assert!(!short.spilled());
+        assert_eq!(short.len(), 8);
+        let padded: [u8; 16] = encode("user-alice").unwrap();
+        assert_eq!(short[..], padded[..8]);
+        assert!(padded[8..].iter().all(|&b| b == 0));
+
+        let full = encode_small(&"a".repeat(21)).unwrap();
+        assert!(!full.spilled());
+        assert_eq!(full[..], encode::<16>(&"a".repeat(21)).unwrap());
+
+        let input = "a-much-longer-identifier-than-sixteen-bytes";
+        let long = encode_small(input).unwrap();
+        assert!(long.spilled());
+        assert_eq!(long.len(), 33);
+        assert_eq!(long[..], encode::<64>(input).unwrap()[..33]);
+
+        assert_eq!(encode_small("ab"), Err(Error::StringTooShort(3)));
+        assert_eq!(
+            encode_small(&"a".repeat(342)),
+            Err(Error::StringTooLong(341))
+        );
+    }
+
     #[test]
     fn test_encode_quick_valid() {
         let input = "test";
@@ -628,6 +913,15 @@ mod tests {
         assert_eq!(encoded.len(), 12);
     }
 
+    #[test]
+    fn test_encode_is_lossy() {
+        assert!(!encode_is_lossy("foo"));
+        assert!(!encode_is_lossy("foo-bar_123"));
+        assert!(!encode_is_lossy(""));
+        assert!(encode_is_lossy("Foo"));
+        assert!(encode_is_lossy("fooBAR"));
+    }
+
     #[test]
     fn test_encode_quick_non16() {
         let input = "abc";
@@ -648,6 +942,102 @@ mod tests {
         assert_eq!(res, Err(Error::InvalidCharacter));
     }
 
+    #[test]
+    fn test_encode_segment_rule() {
+        let config = Config::<16>::builder()
+            .segments(Some(
+                hexaurl_config::SegmentRule::builder()
+                    .min_segments(Some(2))
+                    .build(),
+            ))
+            .build()
+            .unwrap();
+        assert!(encode_with_config::<16>("ab-cd", &config).is_ok());
+        assert_eq!(
+            encode_with_config::<16>("abcd", &config),
+            Err(Error::SegmentCount(1))
+        );
+    }
+
+    #[test]
+    fn test_encode_min_distinct_chars() {
+        let config = Config::<16>::builder()
+            .min_distinct_chars(Some(2))
+            .build()
+            .unwrap();
+        assert!(encode_with_config::<16>("abab", &config).is_ok());
+        assert_eq!(
+            encode_with_config::<16>("aaaaaa", &config),
+            Err(Error::InsufficientVariety(1))
+        );
+    }
+
+    #[test]
+    fn test_encode_reserved() {
+        let config = Config::<16>::builder()
+            .reserved(&["admin"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            encode_with_config::<16>("ADMIN", &config),
+            Err(Error::Reserved)
+        );
+        assert!(encode_with_config::<16>("administrator", &config).is_ok());
+    }
+
+    #[test]
+    fn test_encode_unify_delimiters() {
+        let config = Config::<16>::builder()
+            .unify_delimiters(Some('-'))
+            .build()
+            .unwrap();
+        let hyphen = encode_with_config::<16>("a-b", &config).unwrap();
+        assert_eq!(encode_with_config::<16>("a_b", &config), Ok(hyphen));
+        assert_eq!(
+            crate::decode::decode_with_config::<16, 21>(&hyphen, &config).unwrap(),
+            "a-b"
+        );
+
+        let config = Config::<16>::builder()
+            .composition(Composition::AlphanumericHyphenUnderscore)
+            .unify_delimiters(Some('_'))
+            .build()
+            .unwrap();
+        let underscore = encode_with_config::<16>("foo_bar", &config).unwrap();
+        assert_eq!(encode_with_config::<16>("foo-bar", &config), Ok(underscore));
+        assert_ne!(
+            encode_with_config::<16>("foo-bar", &Config::default()),
+            Ok(underscore)
+        );
+    }
+
+    #[test]
+    fn test_encode_trim_whitespace() {
+        let config = Config::<16>::builder()
+            .trim_whitespace(true)
+            .build()
+            .unwrap();
+        assert_eq!(
+            encode_with_config::<16>(" foo ", &config),
+            encode::<16>("foo")
+        );
+        assert_eq!(
+            encode_with_config::<16>("f o o", &config),
+            Err(Error::InvalidCharacter)
+        );
+        assert_eq!(encode::<16>(" foo "), Err(Error::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_encode_control_character() {
+        assert_eq!(encode::<16>("foo\tbar"), Err(Error::ControlCharacter));
+        assert_eq!(encode::<16>("foo!bar"), Err(Error::InvalidCharacter));
+        assert_eq!(
+            encode_minimal_config::<16>("a\nb"),
+            Err(Error::ControlCharacter)
+        );
+    }
+
     #[test]
     fn test_encode_consecutive_hyphens_error() {
         let input = "--a";
@@ -655,4 +1045,43 @@ mod tests {
         let res = encode_with_config::<16>(input, &config);
         assert_eq!(res, Err(Error::ConsecutiveHyphens));
     }
+
+    #[test]
+    fn test_encode_max_consecutive_delimiters() {
+        use hexaurl_config::{Composition, DelimiterRules};
+
+        let config = Config::<16>::builder()
+            .composition(Composition::AlphanumericHyphenUnderscore)
+            .delimiter(Some(
+                DelimiterRules::builder()
+                    .max_consecutive_hyphens(Some(2))
+                    .max_consecutive_underscores(Some(3))
+                    .build(),
+            ))
+            .build()
+            .unwrap();
+        // Runs crossing the 4-character chunk boundary and in the remainder are both counted.
+        for input in ["abc--de", "abc--de___f", "ab___cdefg--h"] {
+            assert!(encode_with_config::<16>(input, &config).is_ok(), "{input}");
+            assert_eq!(
+                hexaurl_validate::validate_with_config::<16>(input, &config),
+                Ok(())
+            );
+        }
+        assert_eq!(
+            encode_with_config::<16>("abc---de", &config),
+            Err(Error::ConsecutiveHyphens)
+        );
+        assert_eq!(
+            encode_with_config::<16>("abcdefgh____i", &config),
+            Err(Error::ConsecutiveUnderscores)
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "input length 22 exceeds the 21 characters a [u8; 16] can hold")]
+    fn test_encode_unchecked_too_long() {
+        let _: [u8; 16] = unsafe { encode_unchecked(&"a".repeat(22)) };
+    }
 }
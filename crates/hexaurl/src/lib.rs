@@ -5,20 +5,36 @@
 
 pub use hexaurl_config as config;
 pub use hexaurl_validate as validate;
-pub use hexaurl_validate::Error;
+pub use hexaurl_validate::{Error, ErrorKind};
+pub use hexaurl_validate::{max_str_len, min_byte_capacity};
 
+pub mod codec;
 pub mod decode;
 pub mod encode;
+pub mod stats;
 #[cfg(feature = "struct-api")]
 #[cfg_attr(docsrs, doc(cfg(feature = "struct-api")))]
 pub mod struct_api;
 mod utils;
 
 pub use decode::{
-    decode, decode_into, decode_into_with_config, decode_unchecked, decode_unchecked_into,
-    decode_with_config,
+    DecodeState, decode, decode_batch, decode_boxed, decode_into, decode_into_with_config,
+    decode_quick, decode_unchecked, decode_unchecked_into, decode_with_config, sixbit_to_char,
+    validate_bytes,
 };
-pub use encode::{encode, encode_quick, encode_unchecked, encode_with_config};
+#[cfg(feature = "smallvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+pub use encode::encode_small;
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub use encode::par_encode_batch;
+pub use encode::{
+    char_to_sixbit, encode, encode_is_lossy, encode_quick, encode_unchecked, encode_with_config,
+};
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use hexaurl_derive::{HexaUrlNewtype, hexaurl};
+pub use stats::{StorageStats, storage_stats};
 #[cfg(feature = "struct-api")]
 #[cfg_attr(docsrs, doc(cfg(feature = "struct-api")))]
 pub use struct_api::HexaUrl;
@@ -0,0 +1,84 @@
+//! Storage Statistics
+//!
+//! This module provides [`storage_stats`], estimating how many bytes a set of keys takes as
+//! fixed-size HexaURL arrays compared with owned `String`s, for capacity planning.
+
+/// Estimated storage of a set of keys as HexaURL arrays and as `String`s.
+///
+/// Returned by [`storage_stats`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StorageStats {
+    /// Number of keys.
+    pub count: usize,
+    /// Bytes taken by the keys as `[u8; N]` arrays, `count * N`.
+    pub packed_bytes: usize,
+    /// Bytes taken by the keys as `String`s: the string contents plus one `String` header
+    /// (pointer, capacity and length) per key. Allocator overhead is not included.
+    pub string_bytes: usize,
+}
+
+impl StorageStats {
+    /// Returns `packed_bytes / string_bytes`, or `1.0` if there are no keys.
+    ///
+    /// Values below `1.0` mean the packed representation is smaller.
+    pub fn ratio(&self) -> f64 {
+        if self.string_bytes == 0 {
+            return 1.0;
+        }
+        self.packed_bytes as f64 / self.string_bytes as f64
+    }
+
+    /// Returns the number of bytes saved by the packed representation, or `0` if it is not
+    /// smaller.
+    pub fn saved_bytes(&self) -> usize {
+        self.string_bytes.saturating_sub(self.packed_bytes)
+    }
+}
+
+/// Estimates the storage of `inputs` as `[u8; N]` HexaURL arrays compared with `String`s.
+///
+/// The inputs are not validated or encoded; only their lengths are used.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::storage_stats;
+///
+/// let stats = storage_stats::<16>(&["alice", "bob", "carol-smith"]);
+/// assert_eq!(stats.packed_bytes, 48);
+/// assert!(stats.ratio() < 1.0);
+/// ```
+pub fn storage_stats<const N: usize>(inputs: &[&str]) -> StorageStats {
+    let contents: usize = inputs.iter().map(|input| input.len()).sum();
+    StorageStats {
+        count: inputs.len(),
+        packed_bytes: inputs.len() * N,
+        string_bytes: contents + inputs.len() * size_of::<String>(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_storage_stats() {
+        let header = size_of::<String>();
+        let stats = storage_stats::<16>(&["alice", "bob", "carol-smith"]);
+        assert_eq!(
+            stats,
+            StorageStats {
+                count: 3,
+                packed_bytes: 48,
+                string_bytes: 19 + 3 * header,
+            }
+        );
+        assert_eq!(stats.ratio(), 48.0 / (19 + 3 * header) as f64);
+        assert_eq!(stats.saved_bytes(), 19 + 3 * header - 48);
+
+        let empty = storage_stats::<16>(&[]);
+        assert_eq!(empty.packed_bytes, 0);
+        assert_eq!(empty.ratio(), 1.0);
+        assert_eq!(empty.saved_bytes(), 0);
+    }
+}
@@ -26,10 +26,69 @@
 //! For additional information about encoding, decoding, and configuration options,
 //! see the documentation of the underlying [`HexaUrlCore`] struct.
 
-mod core;
+/// Implements [`Borrow<HexaUrlCore>`](std::borrow::Borrow), equality, ordering and hashing
+/// for a wrapper by delegating to its `url` field, so the wrapper keys maps exactly like the
+/// packed HexaURL it holds and can be looked up with one.
+macro_rules! impl_hexaurl_key {
+    ($wrapper:ident) => {
+        impl<const N: usize, const S: usize> ::std::borrow::Borrow<HexaUrlCore<N, S>>
+            for $wrapper<N, S>
+        {
+            #[inline]
+            fn borrow(&self) -> &HexaUrlCore<N, S> {
+                &self.url
+            }
+        }
+
+        impl<const N: usize, const S: usize> PartialEq for $wrapper<N, S> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.url == other.url
+            }
+        }
+
+        impl<const N: usize, const S: usize> Eq for $wrapper<N, S> {}
+
+        impl<const N: usize, const S: usize> PartialOrd for $wrapper<N, S> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<const N: usize, const S: usize> Ord for $wrapper<N, S> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                self.url.cmp(&other.url)
+            }
+        }
+
+        impl<const N: usize, const S: usize> ::std::hash::Hash for $wrapper<N, S> {
+            #[inline]
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                self.url.hash(state);
+            }
+        }
+    };
+}
+
+mod builder;
+pub(crate) mod core;
+#[cfg(feature = "exact")]
+mod exact;
+mod indexed;
+mod map_ext;
+mod validated;
+pub use builder::HexaUrlBuilder;
 #[cfg(feature = "pub-struct-core")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pub-struct-core")))]
 pub use core::*;
+#[cfg(feature = "exact")]
+#[cfg_attr(docsrs, doc(cfg(feature = "exact")))]
+pub use exact::HexaUrlExact;
+pub use indexed::IndexedHexaUrl;
+pub use map_ext::HexaUrlMapExt;
+pub use validated::ValidatedHexaUrl;
 
 /// 8-byte HexaURL:
 /// Supports case-insensitive strings up to 10 characters in length.
@@ -141,6 +200,48 @@ pub type HexaUrl256 = core::HexaUrlCore<256, 341>;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{
+        borrow::Borrow,
+        cmp::Ordering,
+        collections::{BTreeMap, HashMap},
+        fmt::Display,
+        hash::Hash,
+    };
+
+    // Checks that a wrapper from `impl_hexaurl_key!` keys maps by its packed HexaURL.
+    fn assert_keys_like_hexaurl<K>(new: fn(&str) -> K)
+    where
+        K: Borrow<HexaUrl> + Hash + Ord + Display,
+    {
+        let upper = new("Alice");
+        let lower = new("alice");
+        assert!(upper == lower);
+        assert_eq!(upper.cmp(&lower), Ordering::Equal);
+
+        let mut map = HashMap::new();
+        map.insert(upper, 1);
+        assert_eq!(map.insert(lower, 2), Some(1));
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(&HexaUrl::new("ALICE").unwrap()), Some(&2));
+
+        let mut tree = BTreeMap::new();
+        for name in ["carol", "Bob", "alice", "BOB"] {
+            tree.insert(new(name), ());
+        }
+        assert_eq!(tree.len(), 3);
+        assert!(tree.contains_key(&HexaUrl::new("bob").unwrap()));
+        let keys: Vec<HexaUrl> = tree.keys().map(|key| *key.borrow()).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn test_wrapper_keying() {
+        assert_keys_like_hexaurl(|s| IndexedHexaUrl::<16, 21>::new(s).unwrap());
+        #[cfg(feature = "exact")]
+        assert_keys_like_hexaurl(|s| HexaUrlExact::<16, 21>::new(s).unwrap());
+    }
 
     #[test]
     fn test_hexaurl8() {
@@ -190,4 +291,43 @@ mod tests {
         assert_eq!(url.to_string(), "hello");
         assert_eq!(HexaUrl256::capacity(), 341);
     }
+
+    /// Checks that exactly `S` characters round-trip and `S + 1` are rejected.
+    fn assert_capacity_boundary<const N: usize, const S: usize>() {
+        let config = hexaurl_config::Config::<N>::minimal();
+        for len in [S - 1, S] {
+            let input: String = (0..len)
+                .map(|i| char::from(b'a' + (i % 26) as u8))
+                .collect();
+            let url = core::HexaUrlCore::<N, S>::new(&input).unwrap();
+            assert_eq!(url.len(), len, "{N}: {len}");
+            assert_eq!(url.decode().unwrap(), input, "{N}: {len}");
+            let url = core::HexaUrlCore::<N, S>::new_with_config(&input, &config).unwrap();
+            assert_eq!(
+                url.decode_with_config(&config).unwrap(),
+                input,
+                "{N}: {len}"
+            );
+        }
+
+        let too_long = "a".repeat(S + 1);
+        assert_eq!(
+            core::HexaUrlCore::<N, S>::new(&too_long),
+            Err(crate::Error::StringTooLong(S))
+        );
+        assert_eq!(
+            core::HexaUrlCore::<N, S>::new_with_config(&too_long, &config),
+            Err(crate::Error::StringTooLong(S))
+        );
+    }
+
+    #[test]
+    fn test_capacity_boundaries() {
+        assert_capacity_boundary::<8, 10>();
+        assert_capacity_boundary::<16, 21>();
+        assert_capacity_boundary::<32, 42>();
+        assert_capacity_boundary::<64, 85>();
+        assert_capacity_boundary::<128, 170>();
+        assert_capacity_boundary::<256, 341>();
+    }
 }
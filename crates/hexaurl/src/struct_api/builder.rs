@@ -0,0 +1,213 @@
+//! Incremental Builder
+//!
+//! [`HexaUrlBuilder`] collects an identifier from several parts in a stack buffer and
+//! validates it once, when it is built.
+
+use super::core::HexaUrlCore;
+use crate::Error;
+use hexaurl_config::Config;
+use std::str;
+
+/// Incremental builder for [`HexaUrlCore`].
+///
+/// Characters are lowercased and buffered as they are pushed; length, composition and
+/// delimiter rules are checked only by [`build`](Self::build) or
+/// [`build_with_config`](Self::build_with_config). Pushing a non-ASCII character or more
+/// than `S` characters is remembered and reported by `build`, so `Extend` can be used
+/// freely.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::HexaUrl;
+///
+/// let mut builder = HexaUrl::builder();
+/// builder.push_str("Team");
+/// builder.push_delimiter(b'-').unwrap();
+/// builder.extend(['4', '2']);
+/// assert_eq!(builder.build().unwrap().to_string(), "team-42");
+/// ```
+#[derive(Debug, Clone)]
+pub struct HexaUrlBuilder<const N: usize, const S: usize> {
+    buf: [u8; S],
+    len: usize,
+    error: Option<Error>,
+}
+
+impl<const N: usize, const S: usize> HexaUrlBuilder<N, S> {
+    /// Creates an empty builder.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; S],
+            len: 0,
+            error: None,
+        }
+    }
+
+    /// Returns the number of buffered characters.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no characters are buffered.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a string, lowercasing ASCII letters.
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        self.extend(s.chars());
+    }
+
+    /// Appends a delimiter.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] if `d` is neither `b'-'` nor `b'_'`, or
+    /// [`Error::StringTooLong`] if the buffer is full. The builder is left unchanged.
+    pub fn push_delimiter(&mut self, d: u8) -> Result<(), Error> {
+        if d != b'-' && d != b'_' {
+            return Err(Error::InvalidCharacter);
+        }
+        if self.len == S {
+            return Err(Error::StringTooLong(S));
+        }
+        self.buf[self.len] = d;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Validates the buffered string with the default rules and encodes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error recorded while pushing, or an `Error` if the string does
+    /// not satisfy the default validation rules.
+    #[inline]
+    pub fn build(self) -> Result<HexaUrlCore<N, S>, Error> {
+        HexaUrlCore::new(self.as_str()?)
+    }
+
+    /// Validates the buffered string with a custom configuration and encodes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error recorded while pushing, or an `Error` if the string does
+    /// not satisfy `config`.
+    #[inline]
+    pub fn build_with_config(self, config: &Config<N>) -> Result<HexaUrlCore<N, S>, Error> {
+        HexaUrlCore::new_with_config(self.as_str()?, config)
+    }
+
+    #[inline]
+    fn as_str(&self) -> Result<&str, Error> {
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+        // SAFETY: only ASCII bytes are buffered.
+        Ok(unsafe { str::from_utf8_unchecked(&self.buf[..self.len]) })
+    }
+
+    #[inline]
+    fn push_char(&mut self, c: char) {
+        if self.error.is_some() {
+            return;
+        }
+        if !c.is_ascii() {
+            self.error = Some(Error::InvalidCharacter);
+        } else if self.len == S {
+            self.error = Some(Error::StringTooLong(S));
+        } else {
+            self.buf[self.len] = (c as u8).to_ascii_lowercase();
+            self.len += 1;
+        }
+    }
+}
+
+impl<const N: usize, const S: usize> Default for HexaUrlBuilder<N, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, const S: usize> Extend<char> for HexaUrlBuilder<N, S> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            self.push_char(c);
+        }
+    }
+}
+
+impl<'a, const N: usize, const S: usize> Extend<&'a str> for HexaUrlBuilder<N, S> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            self.push_str(s);
+        }
+    }
+}
+
+impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
+    /// Returns an empty [`HexaUrlBuilder`] for this type.
+    #[inline]
+    pub const fn builder() -> HexaUrlBuilder<N, S> {
+        HexaUrlBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::struct_api::{HexaUrl, HexaUrl8};
+
+    #[test]
+    fn test_build_from_parts() {
+        let mut builder = HexaUrl::builder();
+        builder.extend(["Org", "Name"]);
+        builder.push_delimiter(b'-').unwrap();
+        builder.extend("v2".chars());
+        assert_eq!(builder.len(), 10);
+        assert_eq!(builder.build(), HexaUrl::new("orgname-v2"));
+    }
+
+    #[test]
+    fn test_build_checks_rules() {
+        let mut builder = HexaUrl::builder();
+        builder.push_str("ab");
+        builder.push_delimiter(b'-').unwrap();
+        assert_eq!(builder.clone().build(), Err(Error::LeadingTrailingHyphen));
+
+        let config = Config::<16>::minimal();
+        assert_eq!(
+            builder.build_with_config(&config).unwrap().to_string(),
+            "ab-"
+        );
+
+        assert_eq!(
+            HexaUrl::builder().push_delimiter(b'.'),
+            Err(Error::InvalidCharacter)
+        );
+    }
+
+    #[test]
+    fn test_build_deferred_errors() {
+        let mut builder = HexaUrl::builder();
+        builder.push_str("café");
+        assert_eq!(builder.build(), Err(Error::InvalidCharacter));
+
+        let mut builder = HexaUrl8::builder();
+        builder.push_str("abcdefghij");
+        assert_eq!(
+            builder.clone().push_delimiter(b'-'),
+            Err(Error::StringTooLong(10))
+        );
+        builder.push_str("k");
+        assert_eq!(builder.build(), Err(Error::StringTooLong(10)));
+    }
+}
@@ -2,16 +2,27 @@
 use super::{HexaUrl256, HexaUrl8};
 use crate::{
     decode::{
-        decode, decode_core, decode_into, decode_into_with_config, decode_unchecked,
-        decode_unchecked_into, decode_with_config,
+        decode, decode_boxed, decode_core, decode_into, decode_into_with_config, decode_quick,
+        decode_unchecked, decode_unchecked_into, decode_unit, decode_with_config, sixbit_to_char,
+    },
+    encode::{
+        char_to_sixbit, encode, encode_const, encode_minimal_config, encode_quick,
+        encode_unchecked, encode_with_config,
     },
-    encode::{encode, encode_minimal_config, encode_quick, encode_unchecked, encode_with_config},
     utils::len,
-    validate::validate_minimal_config,
+    validate::{validate_minimal_config, validate_with_config},
     Error, MASK_FOUR_BITS, MASK_SIX_BITS, MASK_TWO_BITS,
 };
 use hexaurl_config::Config;
-use std::{fmt, str};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    io::{self, Read, Write},
+    ops::{Index, Range, RangeFull},
+    str,
+};
 
 /// A wrapper around a fixed-size byte array representing a HexaURL.
 ///
@@ -37,11 +48,21 @@ use std::{fmt, str};
 ///
 /// The encoded bytes have a fixed size determined by the generic parameters.
 ///
+/// The derived [`Ord`] compares the encoded bytes, which gives SIXBIT collation: the
+/// empty padding sorts first, so prefixes sort before longer strings, followed by `-` (13),
+/// digits (16-25), letters (33-58) and `_` (63). This agrees with ASCII order on the
+/// lowercase decoded string except that `_` sorts after letters instead of before them;
+/// use [`cmp_ascii`](Self::cmp_ascii) for true ASCII collation.
+///
+/// `HexaUrlCore` is a plain byte array with no interior mutability or pointers, so it is
+/// `Send`, `Sync`, `UnwindSafe` and `RefUnwindSafe`, and can be shared freely across
+/// threads and async tasks.
+///
 /// # Generic Parameters
 ///
 /// - `N`: The size of the internal byte array storage.
 /// - `S`: The maximum length of the encoded HexaURL string representation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialOrd, Ord)]
 pub struct HexaUrlCore<const N: usize, const S: usize>([u8; N]);
 
 impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
@@ -123,6 +144,11 @@ impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
     ///
     /// <div class="warning">The input string must be ASCII. Otherwise, it causes undefined behavior.</div>
     ///
+    /// # Panics
+    ///
+    /// Panics if the input is longer than `S` bytes; debug builds report the length through
+    /// an assertion.
+    ///
     /// # Arguments
     ///
     /// - `input` - The string to be encoded without validation.
@@ -131,6 +157,98 @@ impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
         Self(unsafe { encode_unchecked(input) })
     }
 
+    /// Encodes a string in a `const` context, for `const` and `static` items.
+    ///
+    /// Only the length and the character set are checked: delimiter placement, the
+    /// composition and the minimum length are not, since the configured validation cannot
+    /// run in `const` context. Check the value at runtime, e.g. with
+    /// [`decode_with_config`](Self::decode_with_config), if it must satisfy a config.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input is longer than `S` characters or contains a character outside
+    /// the HexaURL alphabet. In a `const` or `static` item, this is a compile error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// const DASHBOARD: HexaUrl = HexaUrl::new_const("dashboard");
+    /// assert_eq!(DASHBOARD, HexaUrl::new("dashboard").unwrap());
+    /// ```
+    pub const fn new_const(input: &str) -> Self {
+        let bytes = input.as_bytes();
+        assert!(
+            bytes.len() <= S,
+            "input is too long for this type of HexaURL"
+        );
+        let mut i = 0;
+        while i < bytes.len() {
+            assert!(
+                char_to_sixbit(bytes[i]).is_some(),
+                "invalid character in HexaURL constant"
+            );
+            i += 1;
+        }
+        Self(encode_const(bytes))
+    }
+
+    /// Encodes a string in a `const` context without checking its characters.
+    ///
+    /// <div class="warning">Characters outside the HexaURL alphabet are encoded as zero units,
+    /// producing a value that does not round trip. Use <code>new_const</code> unless the input
+    /// is known to be valid.</div>
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input is longer than `S` characters.
+    #[inline]
+    pub const fn const_new_unchecked(input: &str) -> Self {
+        Self(encode_const(input.as_bytes()))
+    }
+
+    /// Formats `v` into a stack buffer and encodes the result with minimal validation.
+    ///
+    /// This avoids the `format!` + `new` round trip for values such as version tags or
+    /// numeric IDs.
+    ///
+    /// # Arguments
+    ///
+    /// - `v` - The value whose `Display` output is encoded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    /// - The formatted string does not pass the minimal validation.
+    /// - The formatted string is longer than `S` bytes, in which case formatting stops
+    ///   early and [`Error::StringTooLong`] is returned.
+    pub fn from_display(v: &dyn fmt::Display) -> Result<Self, Error> {
+        let mut buf = DisplayBuf::<S>::new();
+        if fmt::write(&mut buf, format_args!("{v}")).is_err() {
+            return Err(Error::StringTooLong(S));
+        }
+        Self::new_minimal_config(buf.as_str()?)
+    }
+
+    /// Formats `v` into a stack buffer and encodes the result using a custom validation
+    /// configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the formatted string does not satisfy `config`, or if it is
+    /// longer than `S` bytes.
+    pub fn from_display_with_config(
+        v: &dyn fmt::Display,
+        config: &Config<N>,
+    ) -> Result<Self, Error> {
+        let mut buf = DisplayBuf::<S>::new();
+        if fmt::write(&mut buf, format_args!("{v}")).is_err() {
+            return Err(Error::StringTooLong(config.effective_max()));
+        }
+        Self::new_with_config(buf.as_str()?, config)
+    }
+
     /// Decodes the `HexaUrlCore` back into a `String` using the default validation rules.
     ///
     /// # Errors
@@ -141,6 +259,72 @@ impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
         decode::<N, S>(&self.0)
     }
 
+    /// Decodes the `HexaUrlCore` into a `Box<str>` using the default validation rules.
+    ///
+    /// See [`decode_boxed`] for when to prefer this over [`decode`](Self::decode).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the decoded string fails the validation checks.
+    #[inline]
+    pub fn decode_boxed(&self) -> Result<Box<str>, Error> {
+        decode_boxed::<N, S>(&self.0)
+    }
+
+    /// Decodes the `HexaUrlCore` using the default validation rules, returning `fallback`
+    /// if validation fails.
+    ///
+    /// Intended for logging and display paths where an empty string from
+    /// `decode().unwrap_or_default()` would be confusing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// assert_eq!(HexaUrl::new("hello").unwrap().decode_or_fallback("<invalid>"), "hello");
+    /// assert_eq!(
+    ///     HexaUrl::new_minimal_config("ab").unwrap().decode_or_fallback("<invalid>"),
+    ///     "<invalid>"
+    /// );
+    /// ```
+    #[inline]
+    pub fn decode_or_fallback(&self, fallback: &str) -> String {
+        self.decode().unwrap_or_else(|_| fallback.to_owned())
+    }
+
+    /// Decodes the `HexaUrlCore` using the default validation rules, computing a string
+    /// from the validation error if it fails.
+    #[inline]
+    pub fn decode_or_else<F: FnOnce(Error) -> String>(&self, f: F) -> String {
+        self.decode().unwrap_or_else(f)
+    }
+
+    /// Decodes the `HexaUrlCore` using the default validation rules, or lists the
+    /// significant bytes as `0x`-prefixed hexadecimal, e.g. `[0x86, 0x20]`, if validation
+    /// fails.
+    ///
+    /// Always returns something printable, but the two forms are not distinguishable in
+    /// general; use [`decode`](Self::decode) when the outcome matters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// assert_eq!(HexaUrl::new("hello").unwrap().decode_lossy(), "hello");
+    /// assert_eq!(HexaUrl::new_minimal_config("ab").unwrap().decode_lossy(), "[0x86, 0x20]");
+    /// ```
+    pub fn decode_lossy(&self) -> String {
+        self.decode().unwrap_or_else(|_| {
+            let bytes: Vec<String> = self.0[..self.byte_len()]
+                .iter()
+                .map(|b| format!("{b:#04x}"))
+                .collect();
+            format!("[{}]", bytes.join(", "))
+        })
+    }
+
     /// Decodes the `HexaUrlCore` into a `String` using a custom validation configuration.
     ///
     /// # Arguments
@@ -187,6 +371,48 @@ impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
         decode_unchecked::<N, S>(&self.0)
     }
 
+    /// Decodes the `HexaUrlCore` into a `String` without re-applying any validation rules.
+    ///
+    /// Equivalent to [`decode_unchecked`](Self::decode_unchecked), and safe for any value
+    /// built through a validating constructor. Unlike [`decode`](Self::decode), it accepts
+    /// values encoded under a looser configuration, e.g. a two-character identifier from
+    /// [`new_minimal_config`](Self::new_minimal_config) that the default minimum length
+    /// would reject.
+    #[inline]
+    pub fn decode_lossless(&self) -> String {
+        decode_unchecked::<N, S>(&self.0)
+    }
+
+    /// Decodes the `HexaUrlCore` without validation, borrowing when no allocation is needed.
+    ///
+    /// SIXBIT packing means the decoded string is never a view of the internal bytes, so
+    /// only the empty string is returned as [`Cow::Borrowed`]; anything else is
+    /// [`Cow::Owned`], with the same contents as [`to_string`](ToString::to_string). The
+    /// `Cow` return type leaves room for borrowing under future encodings that preserve
+    /// byte identity.
+    #[inline]
+    pub fn decode_cow(&self) -> Cow<'static, str> {
+        if self.is_empty() {
+            return Cow::Borrowed("");
+        }
+        let mut dst = [0; S];
+        let decoded = decode_core::<N, S>(&self.0, &mut dst);
+        // SAFETY: decode_core only emits ASCII bytes from the lookup table.
+        Cow::Owned(unsafe { str::from_utf8_unchecked(decoded) }.to_owned())
+    }
+
+    /// Decodes the `HexaUrlCore` into a `String` with only a quick integrity check.
+    ///
+    /// The decoding counterpart of [`new_quick`](Self::new_quick); see [`decode_quick`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the bytes contain a SIXBIT unit that is not a HexaURL character.
+    #[inline]
+    pub fn decode_quick(&self) -> Result<String, Error> {
+        decode_quick::<N, S>(&self.0)
+    }
+
     /// Decodes this value into a caller-provided buffer without validation checks.
     ///
     /// Returns a borrowed string slice into `dst`, avoiding allocation in the decode path.
@@ -201,6 +427,26 @@ impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
         &self.0
     }
 
+    /// Returns the encoded bytes in `range`, or `None` if it is out of bounds.
+    ///
+    /// Every 3 bytes hold 4 characters, so the first `k * 3` bytes compare like the first
+    /// `k * 4` characters. This allows comparing key prefixes without decoding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// let a = HexaUrl::new("user-alice").unwrap();
+    /// let b = HexaUrl::new("user-bob").unwrap();
+    /// assert_eq!(a.get_bytes_range(0..3), b.get_bytes_range(0..3));
+    /// assert_eq!(a.get_bytes_range(8..17), None);
+    /// ```
+    #[inline]
+    pub fn get_bytes_range(&self, range: Range<usize>) -> Option<&[u8]> {
+        self.0.get(range)
+    }
+
     /// Attempts to create a `HexaUrlCore` from a raw byte slice.
     ///
     /// # Errors
@@ -219,6 +465,169 @@ impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
         Ok(Self(arr))
     }
 
+    /// Creates a `HexaUrlCore` from untrusted bytes, requiring them to be canonical.
+    ///
+    /// Stricter than [`try_from_bytes`](Self::try_from_bytes): after minimal validation,
+    /// the decoded string is re-encoded and must reproduce `bytes` exactly. This rejects
+    /// dirty padding after the string and any other bits that decoding ignores.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if minimal validation fails, or [`Error::InvalidByte`] if
+    /// `bytes` is not the canonical encoding of its decoded string.
+    pub fn from_bytes_verified(bytes: &[u8; N]) -> Result<Self, Error> {
+        let value = Self::try_from_bytes(bytes)?;
+        if value.canonicalize().0 != *bytes {
+            return Err(Error::InvalidByte);
+        }
+        Ok(value)
+    }
+
+    /// Returns the encoded bytes with every byte after the string set to `pad`, for
+    /// external systems that expect e.g. space-padded (`0x20`) fixed-width keys.
+    ///
+    /// This is an export-only format: the rest of the API relies on zero bytes marking
+    /// the end of the string, so the result must be read back with
+    /// [`from_padded_bytes`](Self::from_padded_bytes), not used as a `HexaUrlCore`.
+    pub fn to_padded_bytes(self, pad: u8) -> [u8; N] {
+        let mut bytes = self.canonicalize().0;
+        bytes[self.byte_len()..].fill(pad);
+        bytes
+    }
+
+    /// Reads bytes written by [`to_padded_bytes`](Self::to_padded_bytes) with the same
+    /// `pad`.
+    ///
+    /// Every way of restoring the trailing `pad` bytes to zero is tried, and exactly one
+    /// of them must be a canonical encoding, as checked by
+    /// [`from_bytes_verified`](Self::from_bytes_verified).
+    ///
+    /// Padding is not always reversible, because an encoding may itself end in `pad`:
+    /// with `0x20`, `"a0"` encodes as `[0x85]` and `"a2"` as `[0x85, 0x20]`, so both pad
+    /// to the same bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidByte`] if no interpretation, or more than one, is a
+    /// canonical encoding.
+    pub fn from_padded_bytes(bytes: &[u8; N], pad: u8) -> Result<Self, Error> {
+        let mut end = N;
+        while end > 0 && bytes[end - 1] == pad {
+            end -= 1;
+        }
+
+        let mut found = None;
+        for len in end..=N {
+            let mut arr = [0; N];
+            arr[..len].copy_from_slice(&bytes[..len]);
+            if let Ok(value) = Self::from_bytes_verified(&arr) {
+                // With a zero `pad` every length yields the same value.
+                if found.is_some_and(|found| found != value) {
+                    return Err(Error::InvalidByte);
+                }
+                found = Some(value);
+            }
+        }
+        found.ok_or(Error::InvalidByte)
+    }
+
+    /// Writes a compact, variable-length frame: a 1-byte length in characters followed by
+    /// only the significant encoded bytes.
+    ///
+    /// Frames of different sizes can be concatenated and read back with
+    /// [`read_framed`](Self::read_framed).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidInput`] if the string is longer than
+    /// 255 characters, or any error returned by `w`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    /// use std::io::Cursor;
+    ///
+    /// let hex = HexaUrl::new("hello").unwrap();
+    /// let mut buf = Vec::new();
+    /// hex.write_framed(&mut buf).unwrap();
+    /// assert_eq!(buf.len(), 1 + 4);
+    ///
+    /// let read = HexaUrl::read_framed(&mut Cursor::new(buf)).unwrap();
+    /// assert_eq!(read, hex);
+    /// ```
+    pub fn write_framed<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let len = self.len();
+        let Ok(prefix) = u8::try_from(len) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                Error::StringTooLong(u8::MAX as usize),
+            ));
+        };
+        w.write_all(&[prefix])?;
+        w.write_all(&self.0[..framed_byte_len(len)])
+    }
+
+    /// Returns the encoded bytes without the trailing zero padding, for variable-length
+    /// storage such as LSM tree keys.
+    ///
+    /// Read the bytes back with [`from_canonical_bytes`](Self::from_canonical_bytes).
+    /// Unlike [`write_framed`](Self::write_framed), no length is stored; the string simply
+    /// ends where the bytes do.
+    // Borrows from `self`, so it cannot take `self` by value like other `to_*` methods.
+    #[allow(clippy::wrong_self_convention)]
+    #[inline]
+    pub fn to_canonical_bytes(&self) -> &[u8] {
+        &self.0[..self.significant_len()]
+    }
+
+    /// Creates a `HexaUrlCore` from bytes returned by
+    /// [`to_canonical_bytes`](Self::to_canonical_bytes), zero-padding them to `N` bytes.
+    ///
+    /// The bytes are untrusted input, so they are checked by
+    /// [`from_bytes_verified`](Self::from_bytes_verified) after padding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BytesTooLong`] if `bytes` is longer than `N`, or any error from
+    /// [`from_bytes_verified`](Self::from_bytes_verified).
+    #[inline]
+    pub fn from_canonical_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() > N {
+            return Err(Error::BytesTooLong(N));
+        }
+        let mut padded = [0; N];
+        padded[..bytes.len()].copy_from_slice(bytes);
+        Self::from_bytes_verified(&padded)
+    }
+
+    /// Reads a frame written by [`write_framed`](Self::write_framed).
+    ///
+    /// The frame is untrusted input, so the bytes must be the canonical encoding of a
+    /// string of the announced length, as checked by
+    /// [`from_bytes_verified`](Self::from_bytes_verified).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] wrapping an [`Error`] if the
+    /// frame does not fit this type or is not canonical, or any error returned by `r`.
+    pub fn read_framed<R: Read>(r: &mut R) -> io::Result<Self> {
+        let mut prefix = [0; 1];
+        r.read_exact(&mut prefix)?;
+        let len = prefix[0] as usize;
+        if len > S {
+            return Err(Error::StringTooLong(S).into());
+        }
+
+        let mut bytes = [0; N];
+        r.read_exact(&mut bytes[..framed_byte_len(len)])?;
+        let value = Self::from_bytes_verified(&bytes)?;
+        if value.len() != len {
+            return Err(Error::InvalidLength.into());
+        }
+        Ok(value)
+    }
+
     /// Creates a new `HexaUrlCore` from a byte slice without any validation or bounds checking.
     ///
     /// # Safety
@@ -230,6 +639,28 @@ impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
         Self(*bytes)
     }
 
+    /// Returns the empty, all-zero `HexaUrlCore`, also returned by [`Default`].
+    ///
+    /// This represents an absent or unset identifier, e.g. a zero-initialized struct field.
+    /// It fails [`decode`](Self::decode) with the default config, whose minimum length is 3;
+    /// use [`Config::minimal()`] to decode it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::{config::Config, Error, HexaUrl};
+    ///
+    /// let unset = HexaUrl::empty();
+    /// assert!(unset.is_empty());
+    /// assert_eq!(unset, HexaUrl::default());
+    /// assert_eq!(unset.decode(), Err(Error::StringTooShort(3)));
+    /// assert_eq!(unset.decode_with_config(&Config::minimal()).unwrap(), "");
+    /// ```
+    #[inline(always)]
+    pub const fn empty() -> Self {
+        Self([0; N])
+    }
+
     /// Returns the maximum possible length of the encoded `HexaUrlCore` string.
     #[inline(always)]
     pub const fn capacity() -> usize {
@@ -285,98 +716,1093 @@ impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
         }
     }
 
-    /// Returns the length of the byte representation.
+    /// Returns the number of encoded bytes, i.e. the index of the first zero byte.
+    ///
+    /// Together with [`len`](Self::len) this describes the packing: every four characters
+    /// occupy three bytes, so `byte_len()` is roughly `ceil(len() * 3 / 4)`. It can be one
+    /// less when the bits of the last character that spill into a new byte are all zero.
+    ///
+    /// O(log N)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// let hex = HexaUrl::new("hello").unwrap();
+    /// assert_eq!(hex.byte_len(), 4);
+    /// assert_eq!(&hex.as_bytes()[hex.byte_len()..], &[0; 12]);
+    /// ```
     #[inline(always)]
-    fn byte_len(&self) -> usize {
+    pub fn byte_len(&self) -> usize {
         len(&self.0)
     }
 
+    /// Returns the number of bytes up to and including the last non-zero byte.
+    #[inline(always)]
+    fn significant_len(&self) -> usize {
+        self.0.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1)
+    }
+
     /// Returns true if the encoded string representation is empty.
     #[inline(always)]
     pub const fn is_empty(&self) -> bool {
         self.0[0] == 0
     }
-}
 
-impl<const M: usize, const T: usize> HexaUrlCore<M, T> {
-    /// Converts an HexaUrlCore\<M\> to an HexaUrlCore\<N\>. If the length of the bytes being
-    /// converted is greater than N, the extra characters are ignored.
-    /// This operation produces a copy (non-destructive).
-    ///
-    /// # Example
+    /// Returns the first decoded character, or `None` if the string is empty.
     ///
-    ///```ignore
-    ///  let s1: HexaUrlCore<8> = HexaUrlCore::new("abcdefg")?;
-    ///  let s2: HexaUrlCore<16> = s1.resize();
-    ///```
-    pub fn resize<const N: usize, const S: usize>(&self) -> HexaUrlCore<N, S> {
-        let byte_len = self.byte_len();
-        self.resize_core(byte_len)
+    /// Only the leading 6-bit unit is decoded; nothing is allocated.
+    #[inline]
+    pub fn first_char(&self) -> Option<char> {
+        if self.is_empty() {
+            return None;
+        }
+        decode_unit(self.0[0] >> 2)
     }
 
-    /// Version of resize that does not allow string truncation due to length.
-    pub fn reallocate<const N: usize, const S: usize>(&self) -> Option<HexaUrlCore<N, S>> {
-        let byte_len = self.byte_len();
-        if byte_len <= N {
-            Some(self.resize_core(byte_len))
-        } else {
-            None
+    /// Returns the last decoded character, or `None` if the string is empty.
+    ///
+    /// Finds the string length with [`len`](Self::len) and decodes only the unit at that
+    /// position; nothing is allocated.
+    #[inline]
+    pub fn last_char(&self) -> Option<char> {
+        match self.len() {
+            0 => None,
+            len => decode_unit(self.sixbit_unit(len - 1)),
         }
     }
 
-    fn resize_core<const N: usize, const S: usize>(&self, byte_len: usize) -> HexaUrlCore<N, S> {
-        let length = if byte_len < N { byte_len } else { N };
-        let mut arr = [0; N];
-        arr[..length].copy_from_slice(&self.0[..length]);
-        HexaUrlCore(arr)
+    /// Returns the decoded character at `index`, or `None` if `index >= self.len()`.
+    ///
+    /// Only the SIXBIT unit at `index` is unpacked: it starts at bit `index * 6`, i.e. in
+    /// position `index % 4` of the 3-byte group `index / 4`. Apart from the bounds check
+    /// through [`len`](Self::len), this is O(1) and does not decode the string.
+    #[inline]
+    pub fn char_at(&self, index: usize) -> Option<char> {
+        if index >= self.len() {
+            return None;
+        }
+        decode_unit(self.sixbit_unit(index))
     }
-}
 
-impl<const N: usize, const S: usize> fmt::Display for HexaUrlCore<N, S> {
-    /// Formats the `HexaUrlCore` as its decoded string representation.
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut res: [u8; S] = [0; S];
-        let slice = decode_core::<N, S>(&self.0, &mut res);
-        // SAFETY: The function assumes the input is valid and does not contain any null bytes.
-        let str_inner = unsafe { str::from_utf8_unchecked(slice) };
-        f.pad(str_inner)
+    /// Returns the number of leading decoded characters shared with `other`, e.g. for
+    /// showing what changed between an old and a new identifier.
+    ///
+    /// Whole 3-byte chunks (four characters each) are compared first, then the SIXBIT
+    /// units of the first differing chunk, so neither value is decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// let old = HexaUrl::new("team-alpha").unwrap();
+    /// let new = HexaUrl::new("team-beta").unwrap();
+    /// assert_eq!(old.common_prefix_len(&new), 5);
+    /// ```
+    pub fn common_prefix_len(&self, other: &Self) -> usize {
+        let max = self.len().min(other.len());
+        let Some(chunk) = self
+            .0
+            .chunks(3)
+            .zip(other.0.chunks(3))
+            .position(|(a, b)| a != b)
+        else {
+            return max;
+        };
+
+        let mut i = chunk * 4;
+        while i < max && self.sixbit_unit(i) == other.sixbit_unit(i) {
+            i += 1;
+        }
+        i.min(max)
     }
-}
 
-impl<const N: usize, const S: usize> From<HexaUrlCore<N, S>> for String {
-    /// Converts the `HexaUrlCore` into its decoded string representation.
-    #[inline]
-    fn from(value: HexaUrlCore<N, S>) -> String {
-        value.to_string()
+    /// Compares the decoded strings in ASCII order.
+    ///
+    /// The derived [`Ord`] compares the encoded bytes, i.e. SIXBIT codes, which order
+    /// `-` < digits < letters < `_`. ASCII order on the lowercase decoded form is
+    /// `-` < digits < `_` < letters, so the two differ only where `_` meets a letter.
+    ///
+    /// Both values are decoded into stack buffers; nothing is allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::{config::Config, HexaUrl};
+    /// use std::cmp::Ordering;
+    ///
+    /// let config = Config::minimal();
+    /// let underscore = HexaUrl::new_with_config("a_b", &config).unwrap();
+    /// let letter = HexaUrl::new_with_config("aab", &config).unwrap();
+    /// assert_eq!(underscore.cmp(&letter), Ordering::Greater);
+    /// assert_eq!(underscore.cmp_ascii(&letter), Ordering::Less);
+    /// ```
+    pub fn cmp_ascii(&self, other: &Self) -> Ordering {
+        let mut dst = [0; S];
+        let mut other_dst = [0; S];
+        let decoded = decode_core::<N, S>(&self.0, &mut dst);
+        decoded.cmp(decode_core::<N, S>(&other.0, &mut other_dst))
     }
-}
 
-impl<const N: usize, const S: usize> TryFrom<String> for HexaUrlCore<N, S> {
-    type Error = Error;
+    /// Returns the number of `delimiter`-separated segments, counted directly on the
+    /// encoded bytes without decoding.
+    ///
+    /// Each 3-byte chunk holds four SIXBIT units, and `delimiter` is compared against its
+    /// unit value: `b'-'` encodes to 13 (`0b001101`) and `b'_'` to 63 (`0b111111`). The
+    /// result equals `decoded.split(delimiter).count()`, so the empty string has one
+    /// segment, and a `delimiter` outside the HexaURL alphabet always yields one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// let s = HexaUrl::new("org-team-admin").unwrap();
+    /// assert_eq!(s.encoded_segment_count(b'-'), 3);
+    /// assert_eq!(s.encoded_segment_count(b'_'), 1);
+    /// ```
+    pub fn encoded_segment_count(&self, delimiter: u8) -> usize {
+        let Some(unit) = char_to_sixbit(delimiter) else {
+            return 1;
+        };
 
-    /// Attempts to create a `HexaUrlCore` from a String.
+        let mut count = 1;
+        for chunk in self.0.chunks(3) {
+            if chunk[0] == 0 {
+                break;
+            }
+            let b1 = chunk.get(1).copied().unwrap_or(0);
+            let b2 = chunk.get(2).copied().unwrap_or(0);
+            let units = [
+                chunk[0] >> 2,
+                ((chunk[0] & MASK_TWO_BITS) << 4) | (b1 >> 4),
+                ((b1 & MASK_FOUR_BITS) << 2) | (b2 >> 6),
+                b2 & MASK_SIX_BITS,
+            ];
+            count += units.iter().filter(|&&u| u == unit).count();
+        }
+        count
+    }
+
+    /// Returns a copy with every bit after the encoded string cleared.
     ///
-    /// # Errors
+    /// Values created through [`from_slice`](Self::from_slice) or [`Storable`] may carry
+    /// non-zero bits after the string terminator, which decode identically but break
+    /// bitwise [`Eq`], [`Hash`] and [`Ord`]. The string ends at the first zero (or
+    /// otherwise non-HexaURL) SIXBIT unit; everything from that unit onward is zeroed,
+    /// so the result equals a freshly encoded value of the same string.
     ///
-    /// Returns an `Error` if validation fails or conversion is impossible.
+    /// [`Storable`]: https://docs.rs/ic-stable-structures/latest/ic_stable_structures/storable/trait.Storable.html
     #[inline]
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        Self::try_from(&value)
+    pub fn canonicalize(&self) -> Self {
+        let mut dst = [0; S];
+        let decoded = decode_core::<N, S>(&self.0, &mut dst);
+        let end = decoded
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(decoded.len());
+        // SAFETY: decode_core only emits ASCII bytes from the lookup table.
+        unsafe { Self(encode_unchecked(str::from_utf8_unchecked(&decoded[..end]))) }
     }
-}
 
-impl<const N: usize, const S: usize> TryFrom<&String> for HexaUrlCore<N, S> {
-    type Error = Error;
+    /// Returns a copy keeping only the first `n` characters, with every bit from SIXBIT
+    /// unit `n` onward cleared.
+    ///
+    /// This works on the packed bytes without decoding: unit `n` starts at bit `n * 6`, so
+    /// the byte containing it keeps only its leading bits and all later bytes are zeroed.
+    /// For a valid value the result equals the encoding of its `n`-character prefix, which
+    /// makes it usable as the lower bound of a prefix scan over ordered keys. An `n` past
+    /// the end of the string leaves a canonical value unchanged.
+    #[inline]
+    pub fn mask_to_length(&self, n: usize) -> Self {
+        let mut bytes = self.0;
+        let bit = n.saturating_mul(6);
+        let byte = bit / 8;
+        if byte < N {
+            bytes[byte] &= !(u8::MAX >> (bit % 8));
+            bytes[byte + 1..].fill(0);
+        }
+        Self(bytes)
+    }
 
-    /// Attempts to create a `HexaUrlCore` from a String reference.
+    /// Returns the next identifier of the same length that is valid under the default
+    /// configuration, or `None` if there is none.
     ///
-    /// # Errors
+    /// Equivalent to [`succ_with_config`](Self::succ_with_config) with the default config.
     ///
-    /// Returns an `Error` if validation fails or conversion is impossible.
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// let succ = |s: &str| HexaUrl::new(s).unwrap().succ().map(|next| next.to_string());
+    /// assert_eq!(succ("aaa").as_deref(), Some("aab"));
+    /// // `_` is not allowed and `ac-` would end in a hyphen.
+    /// assert_eq!(succ("abz").as_deref(), Some("ac0"));
+    /// assert_eq!(succ("zzz"), None);
+    /// ```
     #[inline]
-    fn try_from(value: &String) -> Result<Self, Self::Error> {
-        Self::new_minimal_config(value)
+    pub fn succ(&self) -> Option<Self> {
+        self.succ_with_config(&Config::default())
+    }
+
+    /// Returns the smallest identifier of the same length that is greater in byte order and
+    /// valid under `config`, or `None` if there is none.
+    ///
+    /// Each character is treated as a digit over the characters the composition of `config`
+    /// allows, in SIXBIT code order (`-`, `0`-`9`, `a`-`z`, `_`). Candidates breaking the
+    /// delimiter rules are skipped without being built: a carry resets lower digits to `-`
+    /// only where a hyphen may stand, and to `0` otherwise. Every other candidate is checked
+    /// against the full `config`, so segment, variety and reserved word rules are skipped
+    /// one candidate at a time.
+    ///
+    /// Returns `None` if the value is empty or its length is outside the length limits of
+    /// `config`. A value that is itself invalid under `config` still yields the next valid
+    /// one.
+    pub fn succ_with_config(&self, config: &Config<N>) -> Option<Self> {
+        let mut dst = [0; S];
+        let len = decode_core::<N, S>(&self.0, &mut dst).len();
+        if len == 0 || len < config.min_length_or_zero() || len > config.effective_max() {
+            return None;
+        }
+        let digits = &mut dst[..len];
+
+        // A digit breaking the rules must be raised before any later one.
+        let mut pos = (0..len)
+            .find(|&i| !succ_fits(config, digits, i))
+            .unwrap_or(len - 1);
+        loop {
+            while !succ_raise(config, digits, pos) {
+                pos = pos.checked_sub(1)?;
+            }
+            for i in pos + 1..len {
+                digits[i] = b'-';
+                if !succ_fits(config, digits, i) {
+                    digits[i] = b'0';
+                }
+            }
+            // SAFETY: every digit is an ASCII byte from the lookup tables.
+            let candidate = unsafe { str::from_utf8_unchecked(digits) };
+            if validate_with_config::<N>(candidate, config).is_ok() {
+                // SAFETY: `candidate` was just validated, and its length was checked above.
+                return Some(unsafe { Self(encode_unchecked(candidate)) });
+            }
+            pos = len - 1;
+        }
+    }
+
+    /// Returns the decoded string serialized as a CSS identifier.
+    ///
+    /// Follows the CSSOM identifier serialization rules: a leading digit, or a digit
+    /// following a leading hyphen, is escaped as a code point (`1a` becomes `\31 a`),
+    /// and a lone hyphen becomes `\-`. All other HexaURL characters are emitted as-is.
+    pub fn to_css_identifier(self) -> String {
+        let mut dst = [0; S];
+        let decoded = decode_core::<N, S>(&self.0, &mut dst);
+        let mut res = String::with_capacity(decoded.len() + 3);
+
+        if decoded == b"-" {
+            res.push_str("\\-");
+            return res;
+        }
+
+        for (i, &b) in decoded.iter().enumerate() {
+            if b.is_ascii_digit() && (i == 0 || (i == 1 && decoded[0] == b'-')) {
+                res.push_str("\\3");
+                res.push(b as char);
+                res.push(' ');
+            } else {
+                res.push(b as char);
+            }
+        }
+        res
+    }
+
+    /// Returns the decoded string with every letter in uppercase, e.g. for display names
+    /// or log messages that show identifiers in uppercase.
+    ///
+    /// The string is decoded into a stack buffer and uppercased in place before the single
+    /// allocation of the result.
+    #[inline]
+    pub fn clone_decoded_uppercase(&self) -> String {
+        let mut dst = [0; S];
+        let decoded = decode_core::<N, S>(&self.0, &mut dst);
+        let mut res = String::with_capacity(decoded.len());
+        res.extend(decoded.iter().map(|&b| b.to_ascii_uppercase() as char));
+        res
+    }
+
+    /// Returns the decoded string with every letter in lowercase.
+    ///
+    /// HexaURL letters are always decoded in lowercase, so this is the same as
+    /// `to_string()`; it exists as the explicit counterpart of
+    /// [`clone_decoded_uppercase`](Self::clone_decoded_uppercase).
+    #[inline]
+    pub fn clone_decoded_lowercase(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the decoded string shortened to at most `max` characters for display.
+    ///
+    /// If the decoded string is longer than `max`, the first `max - 1` characters are kept
+    /// and `'…'` is appended; otherwise it is returned unchanged. Every HexaURL character is
+    /// single-byte ASCII, so truncation never splits a character. A `max` of zero yields an
+    /// empty string.
+    pub fn to_short_string(self, max: usize) -> String {
+        let mut res = self.to_string();
+        if res.len() > max {
+            if max == 0 {
+                res.clear();
+            } else {
+                res.truncate(max - 1);
+                res.push('…');
+            }
+        }
+        res
+    }
+
+    /// Returns the bit layout of the encoded string for debugging.
+    ///
+    /// Each character is shown as its 6-bit SIXBIT unit followed by the decoded
+    /// character, separated by spaces, e.g. `"101000(h) 100101(e)"`. Units that do not
+    /// map to a HexaURL character are annotated with `?`. Bits after the string are omitted.
+    pub fn to_bit_string(self) -> String {
+        let mut dst = [0; S];
+        let decoded = decode_core::<N, S>(&self.0, &mut dst);
+        let mut res = String::with_capacity(decoded.len() * 10);
+
+        for (i, &c) in decoded.iter().enumerate() {
+            if i > 0 {
+                res.push(' ');
+            }
+            let c = if c == 0 { '?' } else { c as char };
+            res.push_str(&format!("{:06b}({c})", self.sixbit_unit(i)));
+        }
+        res
+    }
+
+    /// Returns the `i`-th packed SIXBIT unit.
+    #[inline(always)]
+    fn sixbit_unit(&self, i: usize) -> u8 {
+        let bit = i * 6;
+        let byte = bit / 8;
+        let hi = u16::from(self.0[byte]) << 8;
+        let lo = self.0.get(byte + 1).copied().map_or(0, u16::from);
+        (((hi | lo) >> (10 - bit % 8)) as u8) & MASK_SIX_BITS
+    }
+
+    /// Compares the decoded string with `other` lexically, ignoring ASCII case in `other`.
+    #[inline]
+    fn cmp_str(&self, other: &str) -> Ordering {
+        let mut dst = [0; S];
+        let decoded = decode_core::<N, S>(&self.0, &mut dst);
+        decoded
+            .iter()
+            .copied()
+            .cmp(other.bytes().map(|b| b.to_ascii_lowercase()))
+    }
+
+    /// Returns true if the decoded string equals `other`, ignoring ASCII case in `other`.
+    #[inline]
+    fn eq_str(&self, other: &str) -> bool {
+        let mut dst = [0; S];
+        let decoded = decode_core::<N, S>(&self.0, &mut dst);
+        decoded.eq_ignore_ascii_case(other.as_bytes())
+    }
+}
+
+/// Whether `digits[pos]` may follow `digits[..pos]` under the composition and delimiter
+/// rules of `config`, as the character at `pos` of a `digits.len()`-character string.
+///
+/// Letters and digits always fit, so any prefix that fits can be completed.
+fn succ_fits<const N: usize>(config: &Config<N>, digits: &[u8], pos: usize) -> bool {
+    let c = digits[pos];
+    let rules = config.delimiter_rules_ref();
+    let (allowed, leading, trailing, max_run) = match c {
+        b'-' => (
+            config.allow_hyphen(),
+            rules.allow_leading_hyphens(),
+            rules.allow_trailing_hyphens(),
+            rules.max_hyphen_run(),
+        ),
+        b'_' => (
+            config.allow_underscore(),
+            rules.allow_leading_underscores(),
+            rules.allow_trailing_underscores(),
+            rules.max_underscore_run(),
+        ),
+        _ => return true,
+    };
+    if !allowed || (pos == 0 && !leading) || (pos + 1 == digits.len() && !trailing) {
+        return false;
+    }
+    let run = 1 + digits[..pos].iter().rev().take_while(|&&b| b == c).count();
+    if max_run.is_some_and(|max| run > max) {
+        return false;
+    }
+    match pos.checked_sub(1).map(|i| digits[i]) {
+        Some(prev @ (b'-' | b'_')) if prev != c => rules.allow_adjacent_hyphen_underscore(),
+        _ => true,
+    }
+}
+
+/// Raises `digits[pos]` to the next character in SIXBIT code order that fits, returning
+/// `false` if none does.
+fn succ_raise<const N: usize>(config: &Config<N>, digits: &mut [u8], pos: usize) -> bool {
+    let Some(code) = char_to_sixbit(digits[pos]) else {
+        return false;
+    };
+    for next in (code + 1..=MASK_SIX_BITS).filter_map(sixbit_to_char) {
+        digits[pos] = next;
+        if succ_fits(config, digits, pos) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns the number of bytes holding `len` SIXBIT characters.
+#[inline(always)]
+const fn framed_byte_len(len: usize) -> usize {
+    (len * 6).div_ceil(8)
+}
+
+impl<const M: usize, const T: usize> HexaUrlCore<M, T> {
+    /// Converts an HexaUrlCore\<M\> to an HexaUrlCore\<N\>. If the length of the bytes being
+    /// converted is greater than N, the extra characters are ignored.
+    /// This operation produces a copy (non-destructive).
+    ///
+    /// # Example
+    ///
+    ///```ignore
+    ///  let s1: HexaUrlCore<8> = HexaUrlCore::new("abcdefg")?;
+    ///  let s2: HexaUrlCore<16> = s1.resize();
+    ///```
+    pub fn resize<const N: usize, const S: usize>(&self) -> HexaUrlCore<N, S> {
+        let byte_len = self.byte_len();
+        self.resize_core(byte_len)
+    }
+
+    /// Version of resize that does not allow string truncation due to length.
+    pub fn reallocate<const N: usize, const S: usize>(&self) -> Option<HexaUrlCore<N, S>> {
+        let byte_len = self.byte_len();
+        if byte_len <= N {
+            Some(self.resize_core(byte_len))
+        } else {
+            None
+        }
+    }
+
+    /// Transforms each `delimiter`-separated segment of the decoded string with `f`, then
+    /// rejoins and re-encodes the result into an HexaUrlCore\<N\>.
+    ///
+    /// Segments that are empty after mapping are skipped. The result is validated with the
+    /// minimal configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the rejoined string does not pass minimal validation or does
+    /// not fit into the target size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::struct_api::{HexaUrl, HexaUrl8};
+    ///
+    /// let s1 = HexaUrl::new("alpha-beta-gamma").unwrap();
+    /// let s2: HexaUrl8 = s1.map_segments(b'-', |seg| seg[..1].to_string()).unwrap();
+    /// assert_eq!(s2.to_string(), "a-b-g");
+    /// ```
+    pub fn map_segments<const N: usize, const S: usize, F>(
+        &self,
+        delimiter: u8,
+        mut f: F,
+    ) -> Result<HexaUrlCore<N, S>, Error>
+    where
+        F: FnMut(&str) -> String,
+    {
+        let mut dst = [0; T];
+        let decoded = self.decoded_str(&mut dst);
+
+        let mut joined = String::with_capacity(decoded.len());
+        for segment in decoded.split(char::from(delimiter)) {
+            let mapped = f(segment);
+            if mapped.is_empty() {
+                continue;
+            }
+            if !joined.is_empty() {
+                joined.push(char::from(delimiter));
+            }
+            joined.push_str(&mapped);
+        }
+
+        HexaUrlCore::new_minimal_config(&joined)
+    }
+
+    /// Appends `delimiter` and `suffix` to the decoded string and re-encodes the result
+    /// into an HexaUrlCore\<N\>.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if `suffix` contains non-HexaURL characters, or if the joined
+    /// string does not pass minimal validation or does not fit into the target size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// let s1 = HexaUrl::new("user-profile").unwrap();
+    /// let s2: HexaUrl = s1.with_suffix("v2", b'-').unwrap();
+    /// assert_eq!(s2.to_string(), "user-profile-v2");
+    /// ```
+    pub fn with_suffix<const N: usize, const S: usize>(
+        &self,
+        suffix: &str,
+        delimiter: u8,
+    ) -> Result<HexaUrlCore<N, S>, Error> {
+        validate_minimal_config::<N>(suffix)?;
+        let mut dst = [0; T];
+        let decoded = self.decoded_str(&mut dst);
+        let mut joined = String::with_capacity(decoded.len() + 1 + suffix.len());
+        joined.push_str(decoded);
+        joined.push(char::from(delimiter));
+        joined.push_str(suffix);
+        HexaUrlCore::new_minimal_config(&joined)
+    }
+
+    /// Prepends `prefix` and `delimiter` to the decoded string and re-encodes the result
+    /// into an HexaUrlCore\<N\>.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if `prefix` contains non-HexaURL characters, or if the joined
+    /// string does not pass minimal validation or does not fit into the target size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// let s1 = HexaUrl::new("profile").unwrap();
+    /// let s2: HexaUrl = s1.with_prefix("user", b'_').unwrap();
+    /// assert_eq!(s2.to_string(), "user_profile");
+    /// ```
+    pub fn with_prefix<const N: usize, const S: usize>(
+        &self,
+        prefix: &str,
+        delimiter: u8,
+    ) -> Result<HexaUrlCore<N, S>, Error> {
+        validate_minimal_config::<N>(prefix)?;
+        let mut dst = [0; T];
+        let decoded = self.decoded_str(&mut dst);
+        let mut joined = String::with_capacity(prefix.len() + 1 + decoded.len());
+        joined.push_str(prefix);
+        joined.push(char::from(delimiter));
+        joined.push_str(decoded);
+        HexaUrlCore::new_minimal_config(&joined)
+    }
+
+    /// Returns the `index`-th (0-indexed) `delimiter`-separated segment of the decoded
+    /// string, or `None` if there are not that many segments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// let s = HexaUrl::new("org-team-admin").unwrap();
+    /// assert_eq!(s.get_segment(1, b'-').as_deref(), Some("team"));
+    /// assert_eq!(s.get_segment(3, b'-'), None);
+    /// ```
+    pub fn get_segment(&self, index: usize, delimiter: u8) -> Option<String> {
+        self.decoded_str(&mut [0; T])
+            .split(char::from(delimiter))
+            .nth(index)
+            .map(str::to_owned)
+    }
+
+    /// Returns the index of the first `delimiter`-separated segment of the decoded string
+    /// that matches `pattern` case-insensitively, or `None` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// let s = HexaUrl::new("org-team-admin").unwrap();
+    /// assert_eq!(s.find_segment("TEAM", b'-'), Some(1));
+    /// assert_eq!(s.find_segment("user", b'-'), None);
+    /// ```
+    pub fn find_segment(&self, pattern: &str, delimiter: u8) -> Option<usize> {
+        self.decoded_str(&mut [0; T])
+            .split(char::from(delimiter))
+            .position(|segment| segment.eq_ignore_ascii_case(pattern))
+    }
+
+    /// Returns `true` if any `delimiter`-separated segment of the decoded string matches
+    /// `pattern` case-insensitively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// let s = HexaUrl::new("org-admin-panel").unwrap();
+    /// assert!(s.contains_segment("Admin", b'-'));
+    /// assert!(!s.contains_segment("admin-panel", b'-'));
+    /// ```
+    #[inline]
+    pub fn contains_segment(&self, pattern: &str, delimiter: u8) -> bool {
+        self.find_segment(pattern, delimiter).is_some()
+    }
+
+    /// Swaps the `i`-th and `j`-th (0-indexed) `delimiter`-separated segments of the decoded
+    /// string, then rejoins and re-encodes the result.
+    ///
+    /// The result is validated with the minimal configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SegmentCount`] with the number of segments if either index is out of
+    /// range, or an `Error` if the rejoined string does not pass minimal validation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::{Error, HexaUrl};
+    ///
+    /// let s1 = HexaUrl::new("date-entity-action").unwrap();
+    /// let s2 = s1.swap_segments(0, 1, b'-').unwrap();
+    /// assert_eq!(s2.to_string(), "entity-date-action");
+    /// assert_eq!(s1.swap_segments(0, 3, b'-'), Err(Error::SegmentCount(3)));
+    /// ```
+    pub fn swap_segments(&self, i: usize, j: usize, delimiter: u8) -> Result<Self, Error> {
+        let mut dst = [0; T];
+        let decoded = self.decoded_str(&mut dst);
+
+        let delimiter = char::from(delimiter);
+        let mut segments: Vec<&str> = decoded.split(delimiter).collect();
+        if i >= segments.len() || j >= segments.len() {
+            return Err(Error::SegmentCount(segments.len()));
+        }
+        segments.swap(i, j);
+
+        let mut joined = String::with_capacity(decoded.len());
+        for (index, segment) in segments.into_iter().enumerate() {
+            if index > 0 {
+                joined.push(delimiter);
+            }
+            joined.push_str(segment);
+        }
+        Self::new_minimal_config(&joined)
+    }
+
+    /// Decodes into `buf` and returns the decoded string.
+    #[inline]
+    fn decoded_str<'a>(&self, buf: &'a mut [u8; T]) -> &'a str {
+        let decoded = decode_core::<M, T>(&self.0, buf);
+        // SAFETY: decode_core only emits ASCII bytes from the lookup table.
+        unsafe { str::from_utf8_unchecked(decoded) }
+    }
+
+    fn resize_core<const N: usize, const S: usize>(&self, byte_len: usize) -> HexaUrlCore<N, S> {
+        let length = if byte_len < N { byte_len } else { N };
+        let mut arr = [0; N];
+        arr[..length].copy_from_slice(&self.0[..length]);
+        HexaUrlCore(arr)
+    }
+}
+
+/// Numeric representations of the 16-byte `HexaUrl`, for storage in `BIGINT`/`UUID`-like
+/// database columns.
+///
+/// The bytes are read in big-endian order. The resulting number is an opaque key: it has
+/// no inherent meaning, and numeric order does not match the order of the decoded strings.
+/// It does match the bytewise [`Ord`] of `HexaUrl`, so a `BTreeMap<u128, V>` iterates in
+/// the same order as a `BTreeMap<HexaUrl, V>`.
+impl HexaUrlCore<16, 21> {
+    /// Returns the encoded bytes as a `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// let hex = HexaUrl::new("hello").unwrap();
+    /// let n = hex.to_u128();
+    /// assert_eq!(HexaUrl::from_u128(n), hex);
+    /// ```
+    #[inline]
+    pub const fn to_u128(self) -> u128 {
+        u128::from_be_bytes(self.0)
+    }
+
+    /// Returns the encoded bytes as an `i128`, for databases using signed integers.
+    #[inline]
+    pub const fn to_i128(self) -> i128 {
+        i128::from_be_bytes(self.0)
+    }
+
+    /// Creates a `HexaUrl` from a value returned by [`to_u128`](Self::to_u128).
+    ///
+    /// No validation is performed: a number that did not come from `to_u128` gives a
+    /// value that may fail [`decode`](Self::decode). Use the [`TryFrom<u128>`] impl to
+    /// validate.
+    #[inline]
+    pub const fn from_u128(n: u128) -> Self {
+        Self::from_raw_uuid_bytes(n.to_be_bytes())
+    }
+
+    /// Creates a `HexaUrl` from a value returned by [`to_i128`](Self::to_i128).
+    ///
+    /// No validation is performed, as with [`from_u128`](Self::from_u128).
+    #[inline]
+    pub const fn from_i128(n: i128) -> Self {
+        Self::from_raw_uuid_bytes(n.to_be_bytes())
+    }
+
+    /// Wraps 16 arbitrary bytes, such as a UUID, as a `HexaUrl` without any validation.
+    ///
+    /// **For migration windows only.** This lets a column holding both legacy UUIDs and
+    /// `HexaUrl` keys be read through one type while the data is converted. The result is
+    /// generally not a valid HexaURL: [`decode`](Self::decode) fails, and
+    /// [`Display`](fmt::Display) and [`decode_unchecked`](Self::decode_unchecked) produce
+    /// garbage. Use [`to_raw_bytes`](Self::to_raw_bytes) to get the bytes back unchanged,
+    /// and [`try_from_bytes`](Self::try_from_bytes) for anything that should be a real
+    /// identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexaurl::HexaUrl;
+    ///
+    /// let uuid = 0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8_u128.to_be_bytes();
+    /// let legacy = HexaUrl::from_raw_uuid_bytes(uuid);
+    /// assert_eq!(legacy.to_raw_bytes(), uuid);
+    /// assert!(legacy.decode().is_err());
+    /// ```
+    #[inline]
+    pub const fn from_raw_uuid_bytes(bytes: [u8; 16]) -> Self {
+        // Arbitrary bytes are memory-safe to hold: `decode_core` only ever writes ASCII
+        // from its lookup table or 0, so every decoding path still yields valid UTF-8.
+        Self(bytes)
+    }
+
+    /// Returns the 16 raw bytes, the inverse of
+    /// [`from_raw_uuid_bytes`](Self::from_raw_uuid_bytes).
+    ///
+    /// The bytes are returned unchanged, whether or not they are a valid HexaURL.
+    #[inline]
+    pub const fn to_raw_bytes(self) -> [u8; 16] {
+        self.0
+    }
+}
+
+impl From<HexaUrlCore<16, 21>> for u128 {
+    /// Converts the `HexaUrl` into a `u128`, reading its bytes in big-endian order.
+    ///
+    /// Equivalent to [`HexaUrlCore::to_u128`].
+    #[inline]
+    fn from(value: HexaUrlCore<16, 21>) -> u128 {
+        value.to_u128()
+    }
+}
+
+impl TryFrom<u128> for HexaUrlCore<16, 21> {
+    type Error = Error;
+
+    /// Attempts to create a `HexaUrl` from a big-endian `u128`, such as one returned by
+    /// [`HexaUrlCore::to_u128`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the bytes fail validation, as with
+    /// [`try_from_bytes`](HexaUrlCore::try_from_bytes).
+    #[inline]
+    fn try_from(n: u128) -> Result<Self, Self::Error> {
+        Self::try_from_bytes(&n.to_be_bytes())
+    }
+}
+
+impl From<HexaUrlCore<8, 10>> for u64 {
+    /// Converts the `HexaUrl8` into a `u64`, reading its bytes in big-endian order.
+    ///
+    /// Numeric order matches the bytewise [`Ord`] of `HexaUrl8` for values of any length,
+    /// since a shorter string is zero-padded and so sorts before its extensions. Neither
+    /// matches the ASCII order of the decoded strings: SIXBIT places `-` before digits and
+    /// `_` after letters.
+    #[inline]
+    fn from(value: HexaUrlCore<8, 10>) -> u64 {
+        u64::from_be_bytes(value.0)
+    }
+}
+
+impl TryFrom<u64> for HexaUrlCore<8, 10> {
+    type Error = Error;
+
+    /// Attempts to create a `HexaUrl8` from a big-endian `u64`, such as one produced by
+    /// `u64::from`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the bytes fail validation, as with
+    /// [`try_from_bytes`](HexaUrlCore::try_from_bytes).
+    #[inline]
+    fn try_from(n: u64) -> Result<Self, Self::Error> {
+        Self::try_from_bytes(&n.to_be_bytes())
+    }
+}
+
+/// Fixed-capacity `fmt::Write` target used by [`HexaUrlCore::from_display`].
+///
+/// Writing past `S` bytes fails, which aborts formatting.
+struct DisplayBuf<const S: usize> {
+    buf: [u8; S],
+    len: usize,
+}
+
+impl<const S: usize> DisplayBuf<S> {
+    #[inline(always)]
+    const fn new() -> Self {
+        Self {
+            buf: [0; S],
+            len: 0,
+        }
+    }
+
+    #[inline(always)]
+    fn as_str(&self) -> Result<&str, Error> {
+        // Only whole `&str`s are written, so this never fails in practice.
+        str::from_utf8(&self.buf[..self.len]).map_err(|_| Error::InvalidCharacter)
+    }
+}
+
+impl<const S: usize> fmt::Write for DisplayBuf<S> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > S {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl<const N: usize, const S: usize> Default for HexaUrlCore<N, S> {
+    /// Returns the empty value; see [`HexaUrlCore::empty`].
+    #[inline]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<const N: usize, const S: usize> fmt::Display for HexaUrlCore<N, S> {
+    /// Formats the `HexaUrlCore` as its decoded string representation.
+    ///
+    /// The output consists only of RFC 3986 unreserved characters, so it can be embedded
+    /// in URL paths and query strings without percent-encoding.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut res: [u8; S] = [0; S];
+        let slice = decode_core::<N, S>(&self.0, &mut res);
+        // SAFETY: The function assumes the input is valid and does not contain any null bytes.
+        let str_inner = unsafe { str::from_utf8_unchecked(slice) };
+        f.pad(str_inner)
+    }
+}
+
+/// Compares values of any two sizes by their encoded strings.
+///
+/// The shorter byte array is treated as if zero-extended to the length of the longer one,
+/// so `HexaUrl8::new("hello")? == HexaUrl16::new("hello")?` holds. Between values of the
+/// same size this is plain byte equality, consistent with [`Ord`]. [`Hash`] ignores
+/// trailing zero bytes, so it agrees with this comparison across sizes too.
+///
+/// [`Eq`] is only implemented between values of the same size, as it requires the
+/// comparison to be reflexive within a single type.
+impl<const N: usize, const S: usize, const M: usize, const T: usize> PartialEq<HexaUrlCore<M, T>>
+    for HexaUrlCore<N, S>
+{
+    #[inline]
+    fn eq(&self, other: &HexaUrlCore<M, T>) -> bool {
+        let common = if N < M { N } else { M };
+        self.0[..common] == other.0[..common]
+            && self.0[common..].iter().all(|&b| b == 0)
+            && other.0[common..].iter().all(|&b| b == 0)
+    }
+}
+
+/// Hashes only the significant bytes, up to the last non-zero byte.
+///
+/// Trailing zero bytes are skipped, matching the zero extension in [`PartialEq`] between
+/// sizes, so `HexaUrl8::new("hello")?` and `HexaUrl16::new("hello")?` hash identically.
+impl<const N: usize, const S: usize> Hash for HexaUrlCore<N, S> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0[..self.significant_len()].hash(state);
+    }
+}
+
+/// Compares the decoded string with a string slice.
+///
+/// Decoded strings are always lowercase, so `other` is compared case-insensitively:
+/// `HexaUrl::new("hello")? == "HELLO"` holds.
+impl<const N: usize, const S: usize> PartialEq<str> for HexaUrlCore<N, S> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.eq_str(other)
+    }
+}
+
+impl<const N: usize, const S: usize> PartialEq<&str> for HexaUrlCore<N, S> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.eq_str(other)
+    }
+}
+
+impl<const N: usize, const S: usize> PartialEq<HexaUrlCore<N, S>> for str {
+    #[inline]
+    fn eq(&self, other: &HexaUrlCore<N, S>) -> bool {
+        other.eq_str(self)
+    }
+}
+
+impl<const N: usize, const S: usize> PartialEq<HexaUrlCore<N, S>> for &str {
+    #[inline]
+    fn eq(&self, other: &HexaUrlCore<N, S>) -> bool {
+        other.eq_str(self)
+    }
+}
+
+/// Compares the encoded bytes with a raw byte slice, e.g. a key read from a database.
+///
+/// This is an exact match of all `N` bytes, without validating or decoding `other`; a
+/// slice of any other length is never equal.
+impl<const N: usize, const S: usize> PartialEq<[u8]> for HexaUrlCore<N, S> {
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<const N: usize, const S: usize> PartialEq<&[u8]> for HexaUrlCore<N, S> {
+    #[inline]
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.0 == **other
+    }
+}
+
+impl<const N: usize, const S: usize> PartialEq<HexaUrlCore<N, S>> for [u8] {
+    #[inline]
+    fn eq(&self, other: &HexaUrlCore<N, S>) -> bool {
+        other.0 == *self
+    }
+}
+
+impl<const N: usize, const S: usize> PartialEq<HexaUrlCore<N, S>> for &[u8] {
+    #[inline]
+    fn eq(&self, other: &HexaUrlCore<N, S>) -> bool {
+        other.0 == **self
+    }
+}
+
+/// Compares the decoded string with a string slice in string-lexical (ASCII) order.
+///
+/// This differs from the derived [`Ord`], which compares the encoded bytes and therefore
+/// follows SIXBIT order (e.g. `_` sorts after letters there, but before them here).
+/// `other` is lowercased before comparing, matching the lowercase decoded form, so no
+/// `HexaUrlCore` needs to be allocated for a range bound such as `hex < "m"`.
+impl<const N: usize, const S: usize> PartialOrd<str> for HexaUrlCore<N, S> {
+    #[inline]
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        Some(self.cmp_str(other))
+    }
+}
+
+impl<const N: usize, const S: usize> PartialOrd<&str> for HexaUrlCore<N, S> {
+    #[inline]
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        Some(self.cmp_str(other))
+    }
+}
+
+impl<const N: usize, const S: usize> PartialOrd<HexaUrlCore<N, S>> for str {
+    #[inline]
+    fn partial_cmp(&self, other: &HexaUrlCore<N, S>) -> Option<Ordering> {
+        Some(other.cmp_str(self).reverse())
+    }
+}
+
+impl<const N: usize, const S: usize> PartialOrd<HexaUrlCore<N, S>> for &str {
+    #[inline]
+    fn partial_cmp(&self, other: &HexaUrlCore<N, S>) -> Option<Ordering> {
+        Some(other.cmp_str(self).reverse())
+    }
+}
+
+impl<const N: usize, const S: usize> From<HexaUrlCore<N, S>> for String {
+    /// Converts the `HexaUrlCore` into its decoded string representation.
+    #[inline]
+    fn from(value: HexaUrlCore<N, S>) -> String {
+        value.to_string()
+    }
+}
+
+impl<const N: usize, const S: usize> From<&HexaUrlCore<N, S>> for String {
+    /// Converts a borrowed `HexaUrlCore` into its decoded string representation.
+    #[inline]
+    fn from(value: &HexaUrlCore<N, S>) -> String {
+        value.to_string()
+    }
+}
+
+impl<const N: usize, const S: usize> From<HexaUrlCore<N, S>> for [u8; N] {
+    /// Converts the `HexaUrlCore` into its encoded bytes.
+    #[inline]
+    fn from(value: HexaUrlCore<N, S>) -> [u8; N] {
+        value.0
+    }
+}
+
+impl<const N: usize, const S: usize> From<HexaUrlCore<N, S>> for Vec<u8> {
+    /// Converts the `HexaUrlCore` into its encoded bytes.
+    #[inline]
+    fn from(value: HexaUrlCore<N, S>) -> Vec<u8> {
+        value.0.to_vec()
+    }
+}
+
+impl<const N: usize, const S: usize> TryFrom<String> for HexaUrlCore<N, S> {
+    type Error = Error;
+
+    /// Attempts to create a `HexaUrlCore` from a String.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if validation fails or conversion is impossible.
+    #[inline]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const N: usize, const S: usize> TryFrom<&String> for HexaUrlCore<N, S> {
+    type Error = Error;
+
+    /// Attempts to create a `HexaUrlCore` from a String reference.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if validation fails or conversion is impossible.
+    #[inline]
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        Self::new_minimal_config(value)
     }
 }
 
@@ -460,6 +1886,31 @@ impl<const N: usize, const S: usize> AsRef<[u8]> for HexaUrlCore<N, S> {
     }
 }
 
+impl<const N: usize, const S: usize> Index<Range<usize>> for HexaUrlCore<N, S> {
+    type Output = [u8];
+
+    /// Returns the encoded bytes in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds; see [`get_bytes_range`](HexaUrlCore::get_bytes_range)
+    /// for a non-panicking alternative.
+    #[inline]
+    fn index(&self, range: Range<usize>) -> &[u8] {
+        &self.0[range]
+    }
+}
+
+impl<const N: usize, const S: usize> Index<RangeFull> for HexaUrlCore<N, S> {
+    type Output = [u8];
+
+    /// Returns all encoded bytes.
+    #[inline]
+    fn index(&self, _: RangeFull) -> &[u8] {
+        &self.0
+    }
+}
+
 impl<const N: usize, const S: usize> str::FromStr for HexaUrlCore<N, S> {
     type Err = Error;
 
@@ -557,6 +2008,373 @@ impl<'a, const N: usize, const S: usize> arbitrary::Arbitrary<'a> for HexaUrlCor
     }
 }
 
+#[cfg(feature = "base32")]
+mod base32 {
+    use super::HexaUrlCore;
+    use crate::Error;
+
+    /// Crockford base32 alphabet.
+    const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    /// Calculates the length of the base32 representation of `n` bytes.
+    #[inline(always)]
+    const fn calc_base32_len(n: usize) -> usize {
+        (n * 8).div_ceil(5)
+    }
+
+    /// Maps a Crockford base32 symbol to its value, accepting lowercase and the
+    /// `I`/`L` -> `1`, `O` -> `0` aliases.
+    #[inline(always)]
+    const fn symbol_value(c: u8) -> Option<u8> {
+        match c.to_ascii_uppercase() {
+            c @ b'0'..=b'9' => Some(c - b'0'),
+            b'O' => Some(0),
+            b'I' | b'L' => Some(1),
+            c @ b'A'..=b'H' => Some(c - b'A' + 10),
+            c @ b'J'..=b'K' => Some(c - b'J' + 18),
+            c @ b'M'..=b'N' => Some(c - b'M' + 20),
+            c @ b'P'..=b'T' => Some(c - b'P' + 22),
+            c @ b'V'..=b'Z' => Some(c - b'V' + 27),
+            _ => None,
+        }
+    }
+
+    impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
+        /// Encodes the underlying bytes as Crockford base32.
+        ///
+        /// The result is `ceil(N * 8 / 5)` uppercase characters long and avoids the
+        /// ambiguous letters `I`, `L`, `O` and `U`, which makes it suitable for
+        /// human-visible contexts such as QR codes or manual entry.
+        #[cfg_attr(docsrs, doc(cfg(feature = "base32")))]
+        pub fn to_base32_crockford(self) -> String {
+            let mut res = String::with_capacity(calc_base32_len(N));
+            let mut buffer = 0u16;
+            let mut bits = 0u32;
+
+            for &byte in &self.0 {
+                buffer = (buffer << 8) | u16::from(byte);
+                bits += 8;
+                while bits >= 5 {
+                    bits -= 5;
+                    res.push(ALPHABET[usize::from((buffer >> bits) & 0x1F)] as char);
+                }
+            }
+            if bits > 0 {
+                res.push(ALPHABET[usize::from((buffer << (5 - bits)) & 0x1F)] as char);
+            }
+            res
+        }
+
+        /// Decodes a Crockford base32 string produced by [`to_base32_crockford`](Self::to_base32_crockford).
+        ///
+        /// Decoding is case-insensitive and accepts `I`/`L` for `1` and `O` for `0`.
+        ///
+        /// # Errors
+        ///
+        /// Returns an `Error` if:
+        /// - The input length is not `ceil(N * 8 / 5)`.
+        /// - The input contains a character outside the Crockford alphabet.
+        /// - The decoded bytes do not pass minimal validation.
+        #[cfg_attr(docsrs, doc(cfg(feature = "base32")))]
+        pub fn from_base32_crockford(s: &str) -> Result<Self, Error> {
+            if s.len() != calc_base32_len(N) {
+                return Err(Error::InvalidLength);
+            }
+
+            let mut bytes = [0u8; N];
+            let mut idx = 0usize;
+            let mut buffer = 0u16;
+            let mut bits = 0u32;
+
+            for &c in s.as_bytes() {
+                let value = symbol_value(c).ok_or(Error::InvalidCharacter)?;
+                buffer = (buffer << 5) | u16::from(value);
+                bits += 5;
+                if bits >= 8 {
+                    bits -= 8;
+                    bytes[idx] = (buffer >> bits) as u8;
+                    idx += 1;
+                }
+            }
+
+            Self::try_from_bytes(&bytes)
+        }
+    }
+}
+
+#[cfg(feature = "compact-str")]
+mod compact_str_impl {
+    use super::*;
+    use compact_str::CompactString;
+
+    impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
+        /// Decodes into a [`CompactString`].
+        ///
+        /// `CompactString` stores up to 24 bytes inline, so decoding `HexaUrl8` and
+        /// `HexaUrl16` values (at most 10 and 21 characters) never allocates.
+        #[cfg_attr(docsrs, doc(cfg(feature = "compact-str")))]
+        #[inline]
+        pub fn to_compact_string(self) -> CompactString {
+            let mut dst = [0; S];
+            let decoded = decode_core::<N, S>(&self.0, &mut dst);
+            // SAFETY: decode_core only emits ASCII bytes from the lookup table.
+            CompactString::new(unsafe { str::from_utf8_unchecked(decoded) })
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "compact-str")))]
+    impl<const N: usize, const S: usize> TryFrom<CompactString> for HexaUrlCore<N, S> {
+        type Error = Error;
+
+        /// Attempts to create a `HexaUrlCore` from a `CompactString`.
+        ///
+        /// # Errors
+        ///
+        /// Returns an `Error` if validation fails or conversion is impossible.
+        #[inline]
+        fn try_from(value: CompactString) -> Result<Self, Self::Error> {
+            Self::try_from(value.as_str())
+        }
+    }
+}
+
+#[cfg(feature = "fixedstr")]
+mod fixedstr_impl {
+    use super::*;
+    use fixedstr::str32;
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "fixedstr")))]
+    impl<const N: usize, const S: usize> TryFrom<str32> for HexaUrlCore<N, S> {
+        type Error = Error;
+
+        /// Attempts to create a `HexaUrlCore` from a `fixedstr::str32`.
+        ///
+        /// # Errors
+        ///
+        /// Returns an `Error` if the string contains non-HexaURL characters or does not
+        /// fit into `N` bytes.
+        #[inline]
+        fn try_from(value: str32) -> Result<Self, Self::Error> {
+            Self::try_from(value.as_str())
+        }
+    }
+
+    /// The number of bytes a `str32` holds.
+    const STR32_CAPACITY: usize = 31;
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "fixedstr")))]
+    impl<const N: usize, const S: usize> TryFrom<HexaUrlCore<N, S>> for str32 {
+        type Error = Error;
+
+        /// Decodes the `HexaUrlCore` into a `fixedstr::str32`.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::StringTooLong`] if the decoded string is longer than the 31 bytes
+        /// a `str32` holds, which only the 32-byte and larger types can produce.
+        #[inline]
+        fn try_from(value: HexaUrlCore<N, S>) -> Result<Self, Self::Error> {
+            let mut dst = [0; S];
+            let decoded = value.decoded_str(&mut dst);
+            if decoded.len() > STR32_CAPACITY {
+                return Err(Error::StringTooLong(STR32_CAPACITY));
+            }
+            Ok(str32::make(decoded))
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_impl {
+    use super::*;
+
+    impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
+        /// Decodes into a [`heapless::String`] with capacity `S`.
+        ///
+        /// The string is stored inline and `S` bytes always fit the decoding, e.g. for keys of
+        /// a `heapless::FnvIndexMap`.
+        #[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+        #[inline]
+        pub fn to_heapless(self) -> heapless::String<S> {
+            let mut dst = [0; S];
+            let decoded = decode_core::<N, S>(&self.0, &mut dst);
+            let mut res = heapless::String::new();
+            // SAFETY: decode_core only emits ASCII bytes from the lookup table.
+            res.push_str(unsafe { str::from_utf8_unchecked(decoded) })
+                .expect("decoded string fits into S bytes");
+            res
+        }
+    }
+}
+
+#[cfg(feature = "subtle")]
+mod subtle {
+    use super::HexaUrlCore;
+    use subtle::{Choice, ConstantTimeEq};
+
+    impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
+        /// Compares all `N` bytes in constant time.
+        ///
+        /// Unlike `==`, which may exit at the first differing byte, the running time does
+        /// not depend on the contents. This only matters when the value is a secret, such as
+        /// a capability token; map keys and other public identifiers should use `==`.
+        #[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+        #[inline]
+        pub fn ct_eq(&self, other: &Self) -> Choice {
+            self.0.ct_eq(&other.0)
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+    impl<const N: usize, const S: usize> ConstantTimeEq for HexaUrlCore<N, S> {
+        #[inline]
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.0.ct_eq(&other.0)
+        }
+    }
+}
+
+#[cfg(feature = "url")]
+mod url_impl {
+    use super::*;
+    use percent_encoding::percent_decode_str;
+
+    impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
+        /// Percent-decodes `input`, then validates and encodes it with the default
+        /// configuration, so `foo%2Dbar` is accepted as `foo-bar`.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::InvalidCharacter`] if the decoded bytes are not UTF-8, and any
+        /// validation error for the decoded string, e.g. for `%20`.
+        #[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+        #[inline]
+        pub fn new_from_url_encoded(input: &str) -> Result<Self, Error> {
+            Self::new_from_url_encoded_with_config(input, &Config::default())
+        }
+
+        /// Percent-decodes `input`, then validates and encodes it with `config`.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::InvalidCharacter`] if the decoded bytes are not UTF-8, and any
+        /// validation error for the decoded string.
+        #[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+        #[inline]
+        pub fn new_from_url_encoded_with_config(
+            input: &str,
+            config: &Config<N>,
+        ) -> Result<Self, Error> {
+            let decoded = percent_decode_str(input)
+                .decode_utf8()
+                .map_err(|_| Error::InvalidCharacter)?;
+            Self::new_with_config(&decoded, config)
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use super::*;
+
+    impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
+        /// Returns the decoded string as a JSON string literal, e.g. `"foo-bar"`.
+        ///
+        /// HexaURL characters never need escaping, so this only adds the quotes.
+        #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+        #[inline]
+        pub fn to_json_string(self) -> String {
+            format!("\"{self}\"")
+        }
+
+        /// Parses a JSON string literal such as `"foo-bar"` and encodes its contents with the
+        /// default configuration.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::InvalidCharacter`] if `s` is not enclosed in double quotes or
+        /// contains an escape sequence, including `\uXXXX`, and any validation error for the
+        /// unquoted string.
+        #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+        pub fn from_json_string(s: &str) -> Result<Self, Error> {
+            let inner = s
+                .strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .ok_or(Error::InvalidCharacter)?;
+            if inner.contains('\\') {
+                return Err(Error::InvalidCharacter);
+            }
+            Self::new(inner)
+        }
+    }
+}
+
+#[cfg(feature = "similarity")]
+mod similarity {
+    use super::HexaUrlCore;
+
+    impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
+        /// Returns a similarity score in `[0.0, 1.0]`: the ratio of equal bytes to significant
+        /// bytes, taken over the longer [`byte_len`](Self::byte_len) of the two values.
+        ///
+        /// The bytes are compared position by position, so this is a cheap stand-in for an edit
+        /// distance: values sharing a prefix or differing in a few characters score high, but an
+        /// insertion near the start shifts every following byte and scores low. Equal values
+        /// score `1.0`, including two empty values.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use hexaurl::HexaUrl;
+        ///
+        /// let a = HexaUrl::new("user-profile").unwrap();
+        /// let b = HexaUrl::new("user-profiles").unwrap();
+        /// let c = HexaUrl::new("billing").unwrap();
+        /// assert_eq!(a.approx_similarity(&a), 1.0);
+        /// assert!(a.approx_similarity(&b) > a.approx_similarity(&c));
+        /// ```
+        #[cfg_attr(docsrs, doc(cfg(feature = "similarity")))]
+        pub fn approx_similarity(&self, other: &Self) -> f64 {
+            let total = self.byte_len().max(other.byte_len());
+            if total == 0 {
+                return 1.0;
+            }
+            let matching = self.as_bytes()[..total]
+                .iter()
+                .zip(&other.as_bytes()[..total])
+                .filter(|(a, b)| a == b)
+                .count();
+            matching as f64 / total as f64
+        }
+
+        /// Returns the candidate with the highest [`approx_similarity`](Self::approx_similarity)
+        /// to `self`, or `None` if `candidates` is empty.
+        ///
+        /// Ties are resolved in favor of the earliest candidate.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use hexaurl::HexaUrl;
+        ///
+        /// let typo = HexaUrl::new("setings").unwrap();
+        /// let candidates = ["profile", "settings", "billing"].map(|s| HexaUrl::new(s).unwrap());
+        /// assert_eq!(typo.most_similar(&candidates), Some(&candidates[1]));
+        /// ```
+        #[cfg_attr(docsrs, doc(cfg(feature = "similarity")))]
+        pub fn most_similar<'a>(&self, candidates: &'a [Self]) -> Option<&'a Self> {
+            let mut best: Option<(&'a Self, f64)> = None;
+            for candidate in candidates {
+                let score = self.approx_similarity(candidate);
+                if best.is_none_or(|(_, best_score)| score > best_score) {
+                    best = Some((candidate, score));
+                }
+            }
+            best.map(|(candidate, _)| candidate)
+        }
+    }
+}
+
 #[cfg(feature = "candid")]
 mod candid {
     use super::HexaUrlCore;
@@ -616,6 +2434,19 @@ mod tests {
     use serde_json;
     use std::collections::{BTreeMap, HashMap};
 
+    // Guards the auto traits documented on `HexaUrlCore`.
+    mod auto_traits {
+        use super::*;
+        use crate::HexaUrl;
+        use static_assertions::assert_impl_all;
+        use std::panic::{RefUnwindSafe, UnwindSafe};
+
+        assert_impl_all!(HexaUrl: Send, Sync, Copy, Clone);
+        assert_impl_all!(HexaUrl: UnwindSafe, RefUnwindSafe);
+        assert_impl_all!(HexaUrl8: Send, Sync, UnwindSafe, RefUnwindSafe);
+        assert_impl_all!(HexaUrl256: Send, Sync, UnwindSafe, RefUnwindSafe);
+    }
+
     /// Tests encoding and decoding of a string using the default configuration.
     #[test]
     fn test_encode_decode() {
@@ -634,6 +2465,15 @@ mod tests {
         assert_eq!(input, decoded);
     }
 
+    /// Tests quick encoding and decoding of a string.
+    #[test]
+    fn test_encode_decode_quick() {
+        let input = "-hello__";
+        let hexaurl = HexaUrlCore::<16, 21>::new_quick(input).unwrap();
+        assert!(hexaurl.decode().is_err());
+        assert_eq!(hexaurl.decode_quick().unwrap(), input);
+    }
+
     /// Tests the unchecked encoding and decoding of a string.
     #[test]
     fn test_encode_decode_unchecked() {
@@ -655,25 +2495,459 @@ mod tests {
         assert_eq!(map.get(&hexaurl), Some(&input));
     }
 
-    /// Tests that `HexaUrl` implements ordering correctly by using it as a key in a BTreeMap.
+    /// Tests that `HexaUrl` implements ordering correctly by using it as a key in a BTreeMap.
+    #[test]
+    fn test_btree_map() {
+        let input = "hello";
+        let hexaurl = HexaUrlCore::<16, 21>::new(input).unwrap();
+        let mut map = BTreeMap::new();
+        map.insert(hexaurl, input);
+        assert_eq!(map.get(&hexaurl), Some(&input));
+    }
+
+    /// Tests the ordering between two `HexaUrl` values created from different strings.
+    #[test]
+    fn test_ordering() {
+        let input1 = "hello";
+        let input2 = "world";
+        let hexaurl1 = HexaUrlCore::<16, 21>::new(input1).unwrap();
+        let hexaurl2 = HexaUrlCore::<16, 21>::new(input2).unwrap();
+        assert!(hexaurl1 < hexaurl2);
+        assert_eq!(hexaurl1 < hexaurl2, input1 < input2);
+    }
+
+    /// Tests string-lexical comparison against string literals.
+    #[test]
+    fn test_str_comparison() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        assert!(hexaurl == "hello");
+        assert!(hexaurl == "HeLLo");
+        assert!("hello" == hexaurl);
+        assert!(hexaurl != "hello-world");
+
+        // Literals differing only beyond the value's length.
+        assert!(hexaurl < "hello-world");
+        assert!(hexaurl < "hello0");
+        assert!(hexaurl > "hell");
+        assert!("hello-world" > hexaurl);
+        assert!(*"hell" < hexaurl);
+        assert!(hexaurl < "m");
+        assert!(hexaurl >= "HELLO");
+    }
+
+    /// Tests that string comparison follows ASCII order rather than encoded byte order.
+    #[test]
+    fn test_str_comparison_lexical_order() {
+        let underscore = HexaUrlCore::<16, 21>::new_minimal_config("a_b").unwrap();
+        let letter = HexaUrlCore::<16, 21>::new_minimal_config("aab").unwrap();
+        assert!(underscore > letter);
+        assert!(underscore < "aab");
+        assert!(underscore > "a-b");
+    }
+
+    /// Tests that cmp_ascii follows ASCII order while cmp follows SIXBIT order.
+    #[test]
+    fn test_cmp_ascii() {
+        let config = Config::<16>::minimal();
+        let new = |s| HexaUrlCore::<16, 21>::new_with_config(s, &config).unwrap();
+
+        // Hyphens, digits and letters collate the same in both orders.
+        for (a, b) in [
+            ("a-b", "a0b"),
+            ("a9", "aa"),
+            ("a-", "a0"),
+            ("ab", "ab-c"),
+            ("0", "a"),
+        ] {
+            assert_eq!(new(a).cmp(&new(b)), Ordering::Less, "{a} {b}");
+            assert_eq!(new(a).cmp_ascii(&new(b)), Ordering::Less, "{a} {b}");
+            assert_eq!(new(a).cmp_ascii(&new(b)), a.cmp(b));
+        }
+
+        // Underscores sort after letters by SIXBIT code, but before them in ASCII.
+        for (a, b) in [("a_b", "aab"), ("_", "z"), ("x_", "xa")] {
+            assert_eq!(new(a).cmp(&new(b)), Ordering::Greater, "{a} {b}");
+            assert_eq!(new(a).cmp_ascii(&new(b)), Ordering::Less, "{a} {b}");
+        }
+        assert_eq!(new("a_9").cmp_ascii(&new("a_9")), Ordering::Equal);
+    }
+
+    /// Tests that canonicalize clears bits after the terminator.
+    #[test]
+    fn test_canonicalize() {
+        let clean = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        assert_eq!(clean.canonicalize(), clean);
+
+        let mut bytes = *clean.as_bytes();
+        // Trailing bits of the partial chunk that form an invalid SIXBIT unit.
+        bytes[4] = 0x0F;
+        // Garbage after the terminator.
+        bytes[10] = 0xFF;
+        let dirty = unsafe { HexaUrlCore::<16, 21>::from_slice(&bytes) };
+        assert_ne!(dirty, clean);
+        assert_eq!(dirty.to_string(), "hello");
+        assert_eq!(dirty.canonicalize(), clean);
+    }
+
+    /// Tests masking to every prefix length, across all positions within a 3-byte group.
+    #[test]
+    fn test_mask_to_length() {
+        let input = "hello-world";
+        let hexaurl = HexaUrlCore::<16, 21>::new(input).unwrap();
+        let config = Config::<16>::minimal();
+        for n in 0..=input.len() {
+            let prefix = HexaUrlCore::<16, 21>::new_with_config(&input[..n], &config).unwrap();
+            assert_eq!(hexaurl.mask_to_length(n), prefix, "n {n}");
+        }
+        assert_eq!(hexaurl.mask_to_length(21), hexaurl);
+        assert_eq!(hexaurl.mask_to_length(usize::MAX), hexaurl);
+        assert!(hexaurl.mask_to_length(0).is_empty());
+    }
+
+    /// Tests shared prefixes within and across 3-byte chunks.
+    #[test]
+    fn test_common_prefix_len() {
+        let prefix = |a: &str, b: &str| {
+            let config = Config::<16>::minimal();
+            let a = HexaUrlCore::<16, 21>::new_with_config(a, &config).unwrap();
+            let b = HexaUrlCore::<16, 21>::new_with_config(b, &config).unwrap();
+            assert_eq!(a.common_prefix_len(&b), b.common_prefix_len(&a));
+            a.common_prefix_len(&b)
+        };
+        // Within the first chunk.
+        assert_eq!(prefix("abcd", "abxd"), 2);
+        assert_eq!(prefix("abc", "xbc"), 0);
+        // Across chunks.
+        assert_eq!(prefix("abcdefgh", "abcdefxh"), 6);
+        assert_eq!(prefix("abcdefgh", "abcdxfgh"), 4);
+        assert_eq!(prefix("user-alice", "user-alina"), 8);
+        // One value is a prefix of the other.
+        assert_eq!(prefix("abcde", "abcdefghi"), 5);
+        assert_eq!(prefix("", "abc"), 0);
+        // Equal values.
+        let max = "z".repeat(21);
+        assert_eq!(prefix(&max, &max), 21);
+        assert_eq!(prefix("Hello", "hello"), 5);
+    }
+
+    /// Tests construction in `const` and `static` items.
+    #[test]
+    fn test_new_const() {
+        const HELLO: HexaUrlCore<16, 21> = HexaUrlCore::new_const("Hello-World");
+        static SHORT: HexaUrlCore<8, 10> = HexaUrlCore::new_const("ab");
+        const UNCHECKED: HexaUrlCore<16, 21> = HexaUrlCore::const_new_unchecked("hello-world");
+
+        assert_eq!(HELLO, HexaUrlCore::<16, 21>::new("hello-world").unwrap());
+        assert_eq!(UNCHECKED, HELLO);
+        assert_eq!(SHORT.decode_lossless(), "ab");
+        let config = Config::<16>::minimal();
+        assert_eq!(
+            HexaUrlCore::<16, 21>::new_const(&"_".repeat(21)),
+            HexaUrlCore::<16, 21>::new_with_config(&"_".repeat(21), &config).unwrap()
+        );
+    }
+
+    /// Tests that `new_const` rejects characters outside the alphabet.
+    #[test]
+    #[should_panic(expected = "invalid character in HexaURL constant")]
+    fn test_new_const_invalid() {
+        let _ = HexaUrlCore::<16, 21>::new_const("hello world");
+    }
+
+    /// Tests that values encoded under a looser config decode without validation.
+    #[test]
+    fn test_decode_lossless() {
+        let short = HexaUrlCore::<16, 21>::new_minimal_config("ab").unwrap();
+        assert_eq!(short.decode(), Err(Error::StringTooShort(3)));
+        assert_eq!(short.decode_lossless(), "ab");
+
+        let hexaurl = HexaUrlCore::<16, 21>::new("Hello-World").unwrap();
+        assert_eq!(hexaurl.decode_lossless(), hexaurl.decode().unwrap());
+    }
+
+    /// Tests successors, carries and overflow.
+    #[test]
+    fn test_succ() {
+        let succ = |input: &str| {
+            HexaUrlCore::<16, 21>::new(input)
+                .unwrap()
+                .succ()
+                .map(|next| next.to_string())
+        };
+        assert_eq!(succ("aaa").as_deref(), Some("aab"));
+        assert_eq!(succ("a-a").as_deref(), Some("a-b"));
+        assert_eq!(succ("abz").as_deref(), Some("ac0"));
+        assert_eq!(succ("a9z").as_deref(), Some("aa0"));
+        assert_eq!(succ("ab9").as_deref(), Some("aba"));
+        assert_eq!(succ("zz9").as_deref(), Some("zza"));
+        assert_eq!(succ("zzz"), None);
+        assert_eq!(succ(&"z".repeat(21)), None);
+
+        let config = Config::<16>::minimal();
+        let succ = |input: &str| {
+            HexaUrlCore::<16, 21>::new_with_config(input, &config)
+                .unwrap()
+                .succ_with_config(&config)
+                .map(|next| next.to_string())
+        };
+        assert_eq!(succ("aaz").as_deref(), Some("aa_"));
+        assert_eq!(succ("aa_").as_deref(), Some("ab-"));
+        assert_eq!(succ("a-").as_deref(), Some("a0"));
+        assert_eq!(succ("z__").as_deref(), Some("_--"));
+        assert_eq!(succ("___"), None);
+        assert_eq!(succ(""), None);
+
+        // Values invalid under the default config step to the next valid one.
+        let hexaurl = HexaUrlCore::<16, 21>::new_with_config("a_b", &config).unwrap();
+        assert_eq!(hexaurl.succ().unwrap(), "b-0");
+        let hexaurl = HexaUrlCore::<16, 21>::new_with_config("ab", &config).unwrap();
+        assert_eq!(hexaurl.succ(), None);
+
+        let hexaurl = HexaUrlCore::<16, 21>::new("abc-xyz").unwrap();
+        assert!(hexaurl.succ().unwrap() > hexaurl);
+        assert_eq!(hexaurl.succ().unwrap().len(), hexaurl.len());
+    }
+
+    /// Tests that `succ` visits every valid 3-character value in order, so each result
+    /// decodes under the default config.
+    #[test]
+    fn test_succ_stays_valid() {
+        let alphabet: Vec<char> = ('-'..='-').chain('0'..='9').chain('a'..='z').collect();
+        let mut valid: Vec<HexaUrlCore<16, 21>> = Vec::new();
+        for a in &alphabet {
+            for b in &alphabet {
+                for c in &alphabet {
+                    if let Ok(hexaurl) = HexaUrlCore::new(&format!("{a}{b}{c}")) {
+                        valid.push(hexaurl);
+                    }
+                }
+            }
+        }
+        valid.sort();
+
+        for pair in valid.windows(2) {
+            let next = pair[0].succ().unwrap();
+            assert_eq!(next, pair[1], "after {}", pair[0]);
+            assert!(next.decode().is_ok());
+        }
+        assert_eq!(valid.last().unwrap().succ(), None);
+    }
+
+    /// Tests that only the empty string is borrowed.
+    #[test]
+    fn test_decode_cow() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("Hello-World").unwrap();
+        let decoded = hexaurl.decode_cow();
+        assert!(matches!(decoded, Cow::Owned(_)));
+        assert_eq!(decoded, "hello-world");
+
+        let empty = HexaUrlCore::<16, 21>::new_minimal_config("").unwrap();
+        assert!(matches!(empty.decode_cow(), Cow::Borrowed("")));
+    }
+
+    /// Tests the infallible decoding fallbacks.
+    #[test]
+    fn test_decode_fallbacks() {
+        let valid = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        assert_eq!(valid.decode_or_fallback("?"), "hello");
+        assert_eq!(valid.decode_or_else(|err| err.to_string()), "hello");
+        assert_eq!(valid.decode_lossy(), "hello");
+
+        // Too short for the default rules.
+        let invalid = HexaUrlCore::<16, 21>::new_minimal_config("ab").unwrap();
+        assert_eq!(invalid.decode_or_fallback("?"), "?");
+        assert_eq!(
+            invalid.decode_or_else(|err| format!("<{err:?}>")),
+            "<StringTooShort(3)>"
+        );
+        assert_eq!(invalid.decode_lossy(), "[0x86, 0x20]");
+
+        // Bytes below 0x10 keep both hex digits.
+        let padded = HexaUrlCore::<16, 21>::new_minimal_config("a0_b").unwrap();
+        assert_eq!(padded.decode_lossy(), "[0x85, 0x0f, 0xe2]");
+
+        let empty = HexaUrlCore::<16, 21>::empty();
+        assert_eq!(empty.decode_lossy(), "[]");
+    }
+
+    /// Tests CSS identifier serialization.
+    #[test]
+    fn test_to_css_identifier() {
+        let config = Config::<16>::minimal();
+        let css = |input: &str| {
+            HexaUrlCore::<16, 21>::new_with_config(input, &config)
+                .unwrap()
+                .to_css_identifier()
+        };
+        assert_eq!(css("hello-world"), "hello-world");
+        assert_eq!(css("1st"), "\\31 st");
+        assert_eq!(css("-1a"), "-\\31 a");
+        assert_eq!(css("a1"), "a1");
+        assert_eq!(css("--x"), "--x");
+        assert_eq!(css("_9"), "_9");
+        assert_eq!(css("-"), "\\-");
+        assert_eq!(css(""), "");
+    }
+
+    /// Tests counting segments on the encoded bytes against splitting the decoded string.
+    #[test]
+    fn test_encoded_segment_count() {
+        let config = Config::<16>::minimal();
+        let inputs = [
+            "",
+            "abc",
+            "a-b",
+            "a-b-c",
+            "-",
+            "--",
+            "a__b_c",
+            "a-b_c-d",
+            "-a-b-c-d-e-f-g-h-i-j",
+        ];
+        for input in inputs {
+            let hexaurl = HexaUrlCore::<16, 21>::new_with_config(input, &config).unwrap();
+            for delimiter in [b'-', b'_'] {
+                assert_eq!(
+                    hexaurl.encoded_segment_count(delimiter),
+                    input.split(char::from(delimiter)).count(),
+                    "{input}"
+                );
+            }
+        }
+
+        let hexaurl = HexaUrlCore::<16, 21>::new("org-team").unwrap();
+        assert_eq!(hexaurl.encoded_segment_count(b'.'), 1);
+        assert_eq!(hexaurl.encoded_segment_count(b'T'), 2);
+    }
+
+    /// Tests encoding the `Display` output of a value.
+    #[test]
+    fn test_from_display() {
+        let hexaurl = HexaUrlCore::<16, 21>::from_display(&12345u32).unwrap();
+        assert_eq!(hexaurl.to_string(), "12345");
+
+        let hexaurl = HexaUrlCore::<16, 21>::from_display(&"v2-RC1").unwrap();
+        assert_eq!(hexaurl.to_string(), "v2-rc1");
+
+        assert_eq!(
+            HexaUrlCore::<8, 10>::from_display(&u64::MAX),
+            Err(Error::StringTooLong(10))
+        );
+        assert_eq!(
+            HexaUrlCore::<16, 21>::from_display(&1.5f32),
+            Err(Error::InvalidCharacter)
+        );
+
+        let config = Config::<16>::default();
+        let hexaurl = HexaUrlCore::<16, 21>::from_display_with_config(&"user-42", &config);
+        assert_eq!(hexaurl.unwrap().to_string(), "user-42");
+        assert_eq!(
+            HexaUrlCore::<16, 21>::from_display_with_config(&7u8, &config),
+            Err(Error::StringTooShort(3))
+        );
+        assert_eq!(
+            HexaUrlCore::<16, 21>::from_display_with_config(&u128::MAX, &config),
+            Err(Error::StringTooLong(21))
+        );
+    }
+
+    /// Tests decoding only the first and last characters.
+    #[test]
+    fn test_first_last_char() {
+        let config = Config::<16>::minimal();
+        let full = "0123456789abcdefghijk";
+        for input in ["a", "ab", "abc", "abcd", "abcde", "-x_", "_9-", full] {
+            let hexaurl = HexaUrlCore::<16, 21>::new_with_config(input, &config).unwrap();
+            assert_eq!(hexaurl.first_char(), input.chars().next(), "{input}");
+            assert_eq!(hexaurl.last_char(), input.chars().last(), "{input}");
+        }
+
+        let empty = HexaUrlCore::<16, 21>::new_with_config("", &config).unwrap();
+        assert_eq!(empty.first_char(), None);
+        assert_eq!(empty.last_char(), None);
+
+        let upper = HexaUrlCore::<16, 21>::new("Hello").unwrap();
+        assert_eq!(upper.first_char(), Some('h'));
+        assert_eq!(upper.last_char(), Some('o'));
+    }
+
+    /// Tests decoding into uppercase and lowercase copies.
+    #[test]
+    fn test_clone_decoded_case() {
+        let config = Config::<16>::minimal();
+        let hexaurl = HexaUrlCore::<16, 21>::new_with_config("Mixed-Case_42", &config).unwrap();
+        assert_eq!(hexaurl.clone_decoded_uppercase(), "MIXED-CASE_42");
+        assert_eq!(hexaurl.clone_decoded_lowercase(), "mixed-case_42");
+        assert_eq!(hexaurl.clone_decoded_lowercase(), hexaurl.to_string());
+
+        let empty = HexaUrlCore::<16, 21>::new_with_config("", &config).unwrap();
+        assert_eq!(empty.clone_decoded_uppercase(), "");
+    }
+
+    /// Tests fetching single characters across 3-byte group boundaries.
+    #[test]
+    fn test_char_at() {
+        let config = Config::<16>::minimal();
+        let input = "abc-efg_ijk0mnopqrstu";
+        let hexaurl = HexaUrlCore::<16, 21>::new_with_config(input, &config).unwrap();
+        for (i, c) in input.chars().enumerate() {
+            assert_eq!(hexaurl.char_at(i), Some(c), "index {i}");
+        }
+        assert_eq!(hexaurl.char_at(21), None);
+
+        let hexaurl = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        assert_eq!(hexaurl.char_at(3), Some('l'));
+        assert_eq!(hexaurl.char_at(4), Some('o'));
+        assert_eq!(hexaurl.char_at(5), None);
+        assert_eq!(hexaurl.char_at(usize::MAX), None);
+    }
+
+    /// Tests reading single characters at every position within a 4-character group.
     #[test]
-    fn test_btree_map() {
-        let input = "hello";
-        let hexaurl = HexaUrlCore::<16, 21>::new(input).unwrap();
-        let mut map = BTreeMap::new();
-        map.insert(hexaurl, input);
-        assert_eq!(map.get(&hexaurl), Some(&input));
+    fn test_char_at_residues() {
+        let config = Config::<16>::minimal();
+        let hexaurl = HexaUrlCore::<16, 21>::new_with_config("a1-zb2_y9", &config).unwrap();
+        // Residues 0..=3 within the first group, then within the second.
+        assert_eq!(hexaurl.char_at(0), Some('a'));
+        assert_eq!(hexaurl.char_at(1), Some('1'));
+        assert_eq!(hexaurl.char_at(2), Some('-'));
+        assert_eq!(hexaurl.char_at(3), Some('z'));
+        assert_eq!(hexaurl.char_at(4), Some('b'));
+        assert_eq!(hexaurl.char_at(5), Some('2'));
+        assert_eq!(hexaurl.char_at(6), Some('_'));
+        assert_eq!(hexaurl.char_at(7), Some('y'));
+        assert!(hexaurl.char_at(8).is_some_and(|c| c.is_ascii_digit()));
+        assert_eq!(hexaurl.char_at(9), None);
     }
 
-    /// Tests the ordering between two `HexaUrl` values created from different strings.
+    /// Tests shortening the decoded string with an ellipsis.
     #[test]
-    fn test_ordering() {
-        let input1 = "hello";
-        let input2 = "world";
-        let hexaurl1 = HexaUrlCore::<16, 21>::new(input1).unwrap();
-        let hexaurl2 = HexaUrlCore::<16, 21>::new(input2).unwrap();
-        assert!(hexaurl1 < hexaurl2);
-        assert_eq!(hexaurl1 < hexaurl2, input1 < input2);
+    fn test_to_short_string() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("verylongidentifier").unwrap();
+        assert_eq!(hexaurl.to_short_string(30), "verylongidentifier");
+        assert_eq!(hexaurl.to_short_string(18), "verylongidentifier");
+        assert_eq!(hexaurl.to_short_string(17), "verylongidentifi…");
+        assert_eq!(hexaurl.to_short_string(1), "…");
+        assert_eq!(hexaurl.to_short_string(0), "");
+    }
+
+    /// Tests the SIXBIT bit layout dump.
+    #[test]
+    fn test_to_bit_string() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        assert_eq!(
+            hexaurl.to_bit_string(),
+            "101000(h) 100101(e) 101100(l) 101100(l) 101111(o)"
+        );
+
+        let hexaurl = HexaUrlCore::<8, 10>::new_minimal_config("a-1_b").unwrap();
+        assert_eq!(
+            hexaurl.to_bit_string(),
+            "100001(a) 001101(-) 010001(1) 111111(_) 100010(b)"
+        );
+
+        let empty = HexaUrlCore::<8, 10>::new_minimal_config("").unwrap();
+        assert_eq!(empty.to_bit_string(), "");
     }
 
     /// Tests successful creation of HexaUrl from a byte slice.
@@ -719,6 +2993,78 @@ mod tests {
         assert_eq!(long_hexaurl.len(), long_input.len());
     }
 
+    /// Tests the byte_len() method against the string length
+    #[test]
+    fn test_byte_len() {
+        let empty = HexaUrlCore::<16, 21>::new_minimal_config("").unwrap();
+        assert_eq!(empty.byte_len(), 0);
+
+        for input in [
+            "abc",
+            "abcd",
+            "hello",
+            "hello-user",
+            "a".repeat(21).as_str(),
+        ] {
+            let hexaurl = HexaUrlCore::<16, 21>::new(input).unwrap();
+            assert_eq!(hexaurl.byte_len(), (input.len() * 3).div_ceil(4), "{input}");
+        }
+
+        // The spilled bits of the trailing '0' are all zero, so no byte is needed for them.
+        let hexaurl = HexaUrlCore::<16, 21>::new("ab0").unwrap();
+        assert_eq!(hexaurl.len(), 3);
+        assert_eq!(hexaurl.byte_len(), 2);
+    }
+
+    #[test]
+    fn test_get_bytes_range() {
+        let a = HexaUrlCore::<16, 21>::new("user-alice").unwrap();
+        let b = HexaUrlCore::<16, 21>::new("user-bob").unwrap();
+        assert_eq!(a.get_bytes_range(0..3), Some(&a.as_bytes()[..3]));
+        assert_eq!(a.get_bytes_range(0..3), b.get_bytes_range(0..3));
+        assert_ne!(a.get_bytes_range(3..6), b.get_bytes_range(3..6));
+        assert_eq!(a.get_bytes_range(0..16).map(<[u8]>::len), Some(16));
+        assert_eq!(a.get_bytes_range(10..17), None);
+        assert_eq!(a.get_bytes_range(4..16).map(<[u8]>::len), Some(12));
+
+        assert_eq!(&a[0..3], &b[0..3]);
+        assert_eq!(&a[..], a.as_bytes());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let a = HexaUrlCore::<16, 21>::new("user-alice").unwrap();
+        let _ = &a[8..17];
+    }
+
+    /// Tests trailing delimiters at exact chunk boundaries, where the last SIXBIT unit
+    /// fills the low six bits of a chunk's final byte.
+    #[test]
+    fn test_trailing_delimiter_chunk_boundary() {
+        let config = Config::<16>::minimal();
+        for delimiter in ['_', '-'] {
+            for len in [4, 8, 12] {
+                let input = format!("{}{delimiter}", "a".repeat(len - 1));
+                let hexaurl = HexaUrlCore::<16, 21>::new_with_config(&input, &config).unwrap();
+                assert_eq!(hexaurl.len(), len, "{input}");
+                assert_eq!(hexaurl.decode_with_config(&config).unwrap(), input);
+                assert_eq!(hexaurl.last_char(), Some(delimiter));
+
+                let doubled = format!("{}{delimiter}{delimiter}", "a".repeat(len - 2));
+                let hexaurl = HexaUrlCore::<16, 21>::new_with_config(&doubled, &config).unwrap();
+                assert_eq!(hexaurl.len(), len, "{doubled}");
+                assert_eq!(hexaurl.decode_with_config(&config).unwrap(), doubled);
+            }
+
+            let full = format!("{}{delimiter}", "a".repeat(9));
+            let config = Config::<8>::minimal();
+            let hexaurl = HexaUrlCore::<8, 10>::new_with_config(&full, &config).unwrap();
+            assert_eq!(hexaurl.len(), 10);
+            assert_eq!(hexaurl.decode_with_config(&config).unwrap(), full);
+        }
+    }
+
     /// Tests resizing to a larger capacity
     #[test]
     fn test_resize_larger() {
@@ -781,6 +3127,19 @@ mod tests {
         assert_eq!(hexaurl1, hexaurl2);
     }
 
+    /// Tests From<&HexaUrlCore> for String
+    #[test]
+    fn test_string_from_ref() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("Hello").unwrap();
+        let owned = String::from(&hexaurl);
+        assert_eq!(owned, "hello");
+        assert_eq!(owned, String::from(hexaurl));
+
+        let keys = [hexaurl];
+        let strings: Vec<String> = keys.iter().map(String::from).collect();
+        assert_eq!(strings, ["hello"]);
+    }
+
     /// Tests as_ref implementations
     #[test]
     fn test_as_ref() {
@@ -790,6 +3149,36 @@ mod tests {
         let _: &[u8] = hexaurl.as_ref();
     }
 
+    /// Tests the empty value returned by empty() and Default
+    #[test]
+    fn test_empty() {
+        let empty = HexaUrlCore::<16, 21>::empty();
+        assert_eq!(empty.as_bytes(), &[0; 16]);
+        assert_eq!(empty, HexaUrlCore::<16, 21>::default());
+        assert_eq!(
+            empty,
+            HexaUrlCore::<16, 21>::new_minimal_config("").unwrap()
+        );
+        assert!(empty.is_empty());
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.to_string(), "");
+
+        assert_eq!(empty.decode(), Err(Error::StringTooShort(3)));
+        assert_eq!(
+            empty.decode_with_config(&Config::minimal()).as_deref(),
+            Ok("")
+        );
+
+        const UNSET: HexaUrlCore<8, 10> = HexaUrlCore::empty();
+        assert!(UNSET.is_empty());
+
+        #[derive(Default)]
+        struct Record {
+            owner: HexaUrlCore<16, 21>,
+        }
+        assert!(Record::default().owner.is_empty());
+    }
+
     /// Tests Display implementation
     #[test]
     fn test_display() {
@@ -798,6 +3187,20 @@ mod tests {
         assert_eq!(hexaurl.to_string(), input);
     }
 
+    /// Tests that Display honors the formatter's width, alignment and precision
+    #[test]
+    fn test_display_padding() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        assert_eq!(format!("{hexaurl:>20}"), format!("{:>20}", "hello"));
+        assert_eq!(format!("{hexaurl:>20}"), "               hello");
+        assert_eq!(format!("{hexaurl:<20}"), "hello               ");
+        assert_eq!(format!("{hexaurl:^20}"), "       hello        ");
+        assert_eq!(format!("{hexaurl:*^9}"), "**hello**");
+        assert_eq!(format!("{hexaurl:8.3}|"), "hel     |");
+        // A width below the length does not truncate.
+        assert_eq!(format!("{hexaurl:>3}"), "hello");
+    }
+
     /// Tests TryFrom<[u8; N]> implementation
     #[test]
     fn test_try_from_array() {
@@ -818,6 +3221,475 @@ mod tests {
         assert_eq!(hexaurl, hexaurl2);
     }
 
+    /// Tests that equality of large values compares every byte.
+    #[test]
+    fn test_eq_256() {
+        let short = HexaUrl256::new("hero").unwrap();
+        assert_eq!(short, HexaUrl256::new("HERO").unwrap());
+        assert_ne!(short, HexaUrl256::new("heron").unwrap());
+        assert_ne!(short, HexaUrl256::new("hera").unwrap());
+
+        let long = "a".repeat(HexaUrl256::capacity());
+        assert_eq!(
+            HexaUrl256::new(&long).unwrap(),
+            HexaUrl256::new(&long).unwrap()
+        );
+
+        // Differences past the encoded string still count.
+        let mut bytes = *short.as_bytes();
+        bytes[255] = 1;
+        let dirty = unsafe { HexaUrl256::from_slice(&bytes) };
+        assert_ne!(short, dirty);
+        assert_eq!(short, dirty.canonicalize());
+    }
+
+    /// Tests transforming each segment into a value of another size.
+    #[test]
+    fn test_map_segments() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("alpha-beta-gamma").unwrap();
+
+        let initials: HexaUrl8 = hexaurl
+            .map_segments(b'-', |segment| segment[..1].to_string())
+            .unwrap();
+        assert_eq!(initials.to_string(), "a-b-g");
+
+        let upper: HexaUrlCore<16, 21> = hexaurl
+            .map_segments(b'-', |segment| segment.to_uppercase())
+            .unwrap();
+        assert_eq!(upper, hexaurl);
+
+        // Empty segments after mapping are skipped.
+        let skipped: HexaUrlCore<16, 21> = hexaurl
+            .map_segments(b'-', |segment| {
+                if segment == "beta" {
+                    String::new()
+                } else {
+                    segment.to_string()
+                }
+            })
+            .unwrap();
+        assert_eq!(skipped.to_string(), "alpha-gamma");
+
+        // The result is validated and must fit the target size.
+        let too_long = hexaurl.map_segments::<8, 10, _>(b'-', |segment| segment.to_string());
+        assert_eq!(too_long, Err(Error::StringTooLong(10)));
+        let invalid = hexaurl.map_segments::<16, 21, _>(b'-', |_| "a.b".to_string());
+        assert_eq!(invalid, Err(Error::InvalidCharacter));
+
+        let underscored = HexaUrlCore::<16, 21>::new_minimal_config("ab_cd").unwrap();
+        let swapped: HexaUrlCore<16, 21> = underscored
+            .map_segments(b'_', |segment| segment.chars().rev().collect())
+            .unwrap();
+        assert_eq!(swapped.to_string(), "ba_dc");
+    }
+
+    /// Tests appending a suffix and prepending a prefix.
+    #[test]
+    fn test_with_suffix_prefix() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("user-profile").unwrap();
+
+        let suffixed: HexaUrlCore<16, 21> = hexaurl.with_suffix("V2", b'-').unwrap();
+        assert_eq!(suffixed.to_string(), "user-profile-v2");
+        let prefixed: HexaUrlCore<32, 42> = hexaurl.with_prefix("app", b'_').unwrap();
+        assert_eq!(prefixed.to_string(), "app_user-profile");
+
+        assert_eq!(
+            hexaurl.with_suffix::<16, 21>("v.2", b'-'),
+            Err(Error::InvalidCharacter)
+        );
+        assert_eq!(
+            hexaurl.with_prefix::<16, 21>("app", b'.'),
+            Err(Error::InvalidCharacter)
+        );
+        assert_eq!(
+            hexaurl.with_suffix::<16, 21>("version10", b'-'),
+            Err(Error::StringTooLong(21))
+        );
+    }
+
+    /// Tests looking up segments by position and value.
+    #[test]
+    fn test_segments_lookup() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("org-team-admin").unwrap();
+
+        assert_eq!(hexaurl.get_segment(0, b'-').as_deref(), Some("org"));
+        assert_eq!(hexaurl.get_segment(2, b'-').as_deref(), Some("admin"));
+        assert_eq!(hexaurl.get_segment(3, b'-'), None);
+        assert_eq!(
+            hexaurl.get_segment(0, b'_').as_deref(),
+            Some("org-team-admin")
+        );
+
+        assert_eq!(hexaurl.find_segment("team", b'-'), Some(1));
+        assert_eq!(hexaurl.find_segment("ADMIN", b'-'), Some(2));
+        assert_eq!(hexaurl.find_segment("adm", b'-'), None);
+        assert!(hexaurl.contains_segment("Admin", b'-'));
+        assert!(!hexaurl.contains_segment("admin", b'_'));
+    }
+
+    /// Tests swapping segments for segment counts from 1 to 5.
+    #[test]
+    fn test_swap_segments() {
+        let words = ["date", "user", "act", "id", "tag"];
+        for count in 1..=5 {
+            let input = words[..count].join("-");
+            let hexaurl = HexaUrlCore::<16, 21>::new(&input).unwrap();
+            for i in 0..count {
+                for j in 0..count {
+                    let mut expected = words[..count].to_vec();
+                    expected.swap(i, j);
+                    let swapped = hexaurl.swap_segments(i, j, b'-').unwrap();
+                    assert_eq!(swapped.to_string(), expected.join("-"), "{input} {i} {j}");
+                }
+            }
+            assert_eq!(
+                hexaurl.swap_segments(0, count, b'-'),
+                Err(Error::SegmentCount(count))
+            );
+            assert_eq!(
+                hexaurl.swap_segments(count, 0, b'-'),
+                Err(Error::SegmentCount(count))
+            );
+        }
+
+        let hexaurl = HexaUrlCore::<16, 21>::new_minimal_config("date-entity_action").unwrap();
+        assert_eq!(
+            hexaurl.swap_segments(0, 1, b'_').unwrap().to_string(),
+            "action_date-entity"
+        );
+    }
+
+    /// Tests From<HexaUrlCore> for byte containers
+    #[test]
+    fn test_into_bytes() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        let array: [u8; 16] = hexaurl.into();
+        assert_eq!(array, *hexaurl.as_bytes());
+        let vec: Vec<u8> = hexaurl.into();
+        assert_eq!(vec, hexaurl.as_bytes());
+
+        fn takes_array<T: Into<[u8; 16]>>(value: T) -> [u8; 16] {
+            value.into()
+        }
+        assert_eq!(takes_array(hexaurl), array);
+    }
+
+    /// Tests that only canonical bytes pass `from_bytes_verified`.
+    #[test]
+    fn test_from_bytes_verified() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        let bytes = *hexaurl.as_bytes();
+        assert_eq!(
+            HexaUrlCore::<16, 21>::from_bytes_verified(&bytes),
+            Ok(hexaurl)
+        );
+
+        // Dirty padding after the terminating chunk.
+        let mut dirty = bytes;
+        dirty[15] = 1;
+        assert!(HexaUrlCore::<16, 21>::try_from_bytes(&dirty).is_ok());
+        assert_eq!(
+            HexaUrlCore::<16, 21>::from_bytes_verified(&dirty),
+            Err(Error::InvalidByte)
+        );
+
+        // A non-HexaURL unit inside the last chunk, ignored by decoding.
+        let mut stray = bytes;
+        stray[5] = 1;
+        assert_eq!(
+            HexaUrlCore::<16, 21>::from_bytes_verified(&stray),
+            Err(Error::InvalidByte)
+        );
+
+        // Failures of minimal validation are reported as-is.
+        let mut invalid = bytes;
+        invalid[0] = 0b0000_0100;
+        assert_eq!(
+            HexaUrlCore::<16, 21>::from_bytes_verified(&invalid),
+            HexaUrlCore::<16, 21>::try_from_bytes(&invalid)
+        );
+        assert!(HexaUrlCore::<16, 21>::from_bytes_verified(&invalid).is_err());
+    }
+
+    /// Tests exporting with a padding byte and reading it back.
+    #[test]
+    fn test_padded_bytes() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        let padded = hexaurl.to_padded_bytes(b' ');
+        assert_eq!(padded[..4], hexaurl.as_bytes()[..4]);
+        assert!(padded[4..].iter().all(|&b| b == b' '));
+        assert_eq!(
+            HexaUrlCore::<16, 21>::from_padded_bytes(&padded, b' '),
+            Ok(hexaurl)
+        );
+
+        let config = Config::<16>::minimal();
+        for input in ["", "ab", "abcd", "-x_", "0123456789abcdefghijk"] {
+            let hexaurl = HexaUrlCore::<16, 21>::new_with_config(input, &config).unwrap();
+            for pad in [0, b' '] {
+                let padded = hexaurl.to_padded_bytes(pad);
+                assert_eq!(
+                    HexaUrlCore::<16, 21>::from_padded_bytes(&padded, pad),
+                    Ok(hexaurl),
+                    "{input} padded with {pad:#04x}"
+                );
+            }
+        }
+
+        // "a2" ends in 0x20, so it pads to the same bytes as "a0".
+        let a0 = HexaUrlCore::<16, 21>::new_minimal_config("a0").unwrap();
+        let a2 = HexaUrlCore::<16, 21>::new_minimal_config("a2").unwrap();
+        assert_eq!(a0.to_padded_bytes(b' '), a2.to_padded_bytes(b' '));
+        assert_eq!(
+            HexaUrlCore::<16, 21>::from_padded_bytes(&a2.to_padded_bytes(b' '), b' '),
+            Err(Error::InvalidByte)
+        );
+    }
+
+    /// Tests round trips of several framed values through one buffer.
+    #[test]
+    fn test_framed_round_trip() {
+        let values = [
+            HexaUrlCore::<16, 21>::new("hello").unwrap(),
+            HexaUrlCore::<16, 21>::new_minimal_config("a").unwrap(),
+            // The second encoded byte of "a0" is zero.
+            HexaUrlCore::<16, 21>::new_minimal_config("a0").unwrap(),
+            HexaUrlCore::<16, 21>::new("ultimate-august-champ").unwrap(),
+        ];
+        let short = HexaUrl8::new("mixed").unwrap();
+
+        let mut buf = Vec::new();
+        for value in &values {
+            value.write_framed(&mut buf).unwrap();
+        }
+        short.write_framed(&mut buf).unwrap();
+        assert_eq!(buf.len(), (1 + 4) + (1 + 1) + (1 + 2) + (1 + 16) + (1 + 4));
+
+        let mut cursor = std::io::Cursor::new(buf);
+        for value in &values {
+            assert_eq!(
+                HexaUrlCore::<16, 21>::read_framed(&mut cursor).unwrap(),
+                *value
+            );
+        }
+        assert_eq!(HexaUrl8::read_framed(&mut cursor).unwrap(), short);
+        assert_eq!(
+            HexaUrl8::read_framed(&mut cursor).unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    /// Tests that malformed frames are rejected.
+    #[test]
+    fn test_framed_invalid() {
+        let mut buf = Vec::new();
+        HexaUrlCore::<16, 21>::new("abcdefghijk")
+            .unwrap()
+            .write_framed(&mut buf)
+            .unwrap();
+        let err = HexaUrl8::read_framed(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        // Length prefix disagreeing with the bytes.
+        let mut buf = Vec::new();
+        HexaUrl8::new("hello")
+            .unwrap()
+            .write_framed(&mut buf)
+            .unwrap();
+        buf[0] = 6;
+        buf.push(0);
+        let err = HexaUrl8::read_framed(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let long = HexaUrl256::new(&"a".repeat(256)).unwrap();
+        let err = long.write_framed(&mut Vec::new()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    /// Tests the trimmed byte representation.
+    #[test]
+    fn test_canonical_bytes() {
+        let config = Config::<16>::minimal();
+        let long = "z".repeat(21);
+        for input in ["", "a", "abcd", "hello", "hello-world", &long] {
+            let hexaurl = HexaUrlCore::<16, 21>::new_with_config(input, &config).unwrap();
+            let bytes = hexaurl.to_canonical_bytes();
+            // The last byte may be dropped too, if it only held zero bits.
+            assert!(bytes.len() <= framed_byte_len(input.len()), "{input}");
+            let restored = HexaUrlCore::<16, 21>::from_canonical_bytes(bytes);
+            assert_eq!(restored, Ok(hexaurl));
+        }
+        let hex8 = HexaUrl8::new("hello").unwrap();
+        assert_eq!(hex8.to_canonical_bytes().len(), 4);
+
+        assert_eq!(
+            HexaUrlCore::<16, 21>::from_canonical_bytes(&[0x84; 17]),
+            Err(Error::BytesTooLong(16))
+        );
+        // Dirty padding bits after "a".
+        assert_eq!(
+            HexaUrlCore::<16, 21>::from_canonical_bytes(&[0x84, 0x01]),
+            Err(Error::InvalidByte)
+        );
+    }
+
+    /// Tests equality between values of different sizes.
+    #[test]
+    fn test_eq_cross_size() {
+        let hex8 = HexaUrl8::new("hello").unwrap();
+        let hex16 = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        let hex256 = HexaUrl256::new("hello").unwrap();
+        assert_eq!(hex8, hex16);
+        assert_eq!(hex16, hex8);
+        assert_eq!(hex8, hex256);
+        assert_eq!(hex256, hex16);
+
+        assert_ne!(hex8, HexaUrlCore::<16, 21>::new("hell").unwrap());
+        assert_ne!(hex8, HexaUrlCore::<16, 21>::new("hellos").unwrap());
+
+        let full = HexaUrl8::new("abcdefghij").unwrap();
+        assert_eq!(full, HexaUrlCore::<16, 21>::new("abcdefghij").unwrap());
+        assert_ne!(full, HexaUrlCore::<16, 21>::new("abcdefghijk").unwrap());
+
+        // Non-zero bits past the shorter array still count.
+        let mut bytes = *hex16.as_bytes();
+        bytes[15] = 1;
+        let dirty = unsafe { HexaUrlCore::<16, 21>::from_slice(&bytes) };
+        assert_ne!(hex8, dirty);
+        assert_ne!(dirty, hex8);
+    }
+
+    /// Tests comparing against raw byte slices.
+    #[test]
+    fn test_eq_bytes() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        let bytes: &[u8] = hexaurl.as_bytes();
+        assert_eq!(hexaurl, bytes);
+        assert_eq!(hexaurl, *bytes);
+        assert_eq!(bytes, hexaurl);
+        assert_eq!(*bytes, hexaurl);
+
+        // Only the exact `N` bytes match, even when the extra bytes are zero.
+        assert_ne!(hexaurl, &bytes[..15]);
+        assert_ne!(hexaurl, [bytes, &[0]].concat().as_slice());
+
+        let other = HexaUrlCore::<16, 21>::new("world").unwrap();
+        assert_ne!(other, bytes);
+    }
+
+    /// Tests that values equal across sizes also hash equally.
+    #[test]
+    fn test_hash_cross_size() {
+        use std::hash::BuildHasher;
+
+        let state = std::collections::hash_map::RandomState::new();
+        let hex8 = HexaUrl8::new("hello").unwrap();
+        let hex16 = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        let hex256 = HexaUrl256::new("hello").unwrap();
+        assert_eq!(hex8, hex16);
+        assert_eq!(state.hash_one(hex8), state.hash_one(hex16));
+        assert_eq!(state.hash_one(hex8), state.hash_one(hex256));
+
+        let other = HexaUrlCore::<16, 21>::new("hellos").unwrap();
+        assert_ne!(state.hash_one(hex16), state.hash_one(other));
+
+        let empty = HexaUrl8::new_minimal_config("").unwrap();
+        let empty16 = HexaUrlCore::<16, 21>::new_minimal_config("").unwrap();
+        assert_eq!(state.hash_one(empty), state.hash_one(empty16));
+    }
+
+    /// Tests the numeric representations of the 16-byte HexaUrl.
+    #[test]
+    fn test_u128_round_trip() {
+        let hexaurl = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        let n = hexaurl.to_u128();
+        assert_eq!(n.to_be_bytes(), *hexaurl.as_bytes());
+        assert_eq!(HexaUrlCore::<16, 21>::from_u128(n), hexaurl);
+
+        let i = hexaurl.to_i128();
+        assert_eq!(i as u128, n);
+        assert_eq!(HexaUrlCore::<16, 21>::from_i128(i), hexaurl);
+
+        let long = HexaUrlCore::<16, 21>::new("ultimate-august-champ").unwrap();
+        assert_eq!(HexaUrlCore::<16, 21>::from_u128(long.to_u128()), long);
+    }
+
+    /// Tests the `u128` conversions and that numeric order follows the bytewise `Ord`.
+    #[test]
+    fn test_u128_conversions() {
+        let config = Config::<16>::minimal();
+        let inputs = ["", "-", "0", "9a", "a", "abc", "abd", "b", "z_", "_", "__z"];
+        let values: Vec<_> = inputs
+            .iter()
+            .map(|input| HexaUrlCore::<16, 21>::new_with_config(input, &config).unwrap())
+            .collect();
+        for &a in &values {
+            let n = u128::from(a);
+            assert_eq!(n, a.to_u128());
+            assert_eq!(HexaUrlCore::<16, 21>::try_from(n), Ok(a));
+            for &b in &values {
+                assert_eq!(n.cmp(&u128::from(b)), a.cmp(&b), "{a} vs {b}");
+            }
+        }
+
+        // "a", the unused SIXBIT unit 1, then "a".
+        let invalid = 0x841840_u128 << 104;
+        assert!(HexaUrlCore::<16, 21>::try_from(invalid).is_err());
+    }
+
+    /// Tests that arbitrary 16-byte values survive the raw migration path unchanged.
+    #[test]
+    fn test_raw_uuid_bytes_round_trip() {
+        let samples = [
+            [0; 16],
+            [0xff; 16],
+            0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8_u128.to_be_bytes(),
+            core::array::from_fn(|i| (i as u8).wrapping_mul(37)),
+        ];
+        for bytes in samples {
+            let raw = HexaUrlCore::<16, 21>::from_raw_uuid_bytes(bytes);
+            assert_eq!(raw.to_raw_bytes(), bytes);
+            assert_eq!(raw.as_bytes(), &bytes);
+            assert_eq!(
+                HexaUrlCore::<16, 21>::from_raw_uuid_bytes(raw.to_raw_bytes()),
+                raw
+            );
+        }
+        let garbage = HexaUrlCore::<16, 21>::from_raw_uuid_bytes([0xff; 16]);
+        assert!(garbage.decode().is_err());
+
+        // Valid encodings pass through the raw path unchanged as well.
+        let hexaurl = HexaUrlCore::<16, 21>::new("hello").unwrap();
+        assert_eq!(
+            HexaUrlCore::<16, 21>::from_raw_uuid_bytes(hexaurl.to_raw_bytes()),
+            hexaurl
+        );
+    }
+
+    /// Tests the `u64` conversions of `HexaUrl8` and that numeric order follows the
+    /// bytewise `Ord`, including between values of different lengths.
+    #[test]
+    fn test_u64_conversions() {
+        let config = Config::<8>::minimal();
+        let inputs = ["", "-", "0", "9a", "a", "a-", "abc", "z_", "_", "zzzzzzzz"];
+        let values: Vec<_> = inputs
+            .iter()
+            .map(|input| HexaUrlCore::<8, 10>::new_with_config(input, &config).unwrap())
+            .collect();
+        for &a in &values {
+            let n = u64::from(a);
+            assert_eq!(n.to_be_bytes(), *a.as_bytes());
+            assert_eq!(HexaUrlCore::<8, 10>::try_from(n), Ok(a));
+            for &b in &values {
+                assert_eq!(n.cmp(&u64::from(b)), a.cmp(&b), "{a} vs {b}");
+            }
+        }
+
+        // "a", the unused SIXBIT unit 1, then "a".
+        let invalid = 0x841840_u64 << 40;
+        assert!(HexaUrlCore::<8, 10>::try_from(invalid).is_err());
+    }
+
     #[cfg(feature = "serde")]
     mod serde_impl {
         use super::*;
@@ -832,6 +3704,48 @@ mod tests {
             assert_eq!(hexaurl, deserialized);
         }
 
+        /// Tests YAML round-trips, including values YAML would otherwise read as non-strings.
+        #[test]
+        fn test_serde_yaml_round_trip() {
+            for input in ["hello", "12345", "true", "null", "1e5", "a-b"] {
+                let hexaurl = HexaUrlCore::<16, 21>::new(input).unwrap();
+                let yaml = serde_yaml::to_string(&hexaurl).unwrap();
+                let deserialized: HexaUrlCore<16, 21> = serde_yaml::from_str(&yaml).unwrap();
+                assert_eq!(hexaurl, deserialized);
+
+                let hexaurl8 = HexaUrlCore::<8, 10>::new(input).unwrap();
+                let yaml = serde_yaml::to_string(&hexaurl8).unwrap();
+                let deserialized: HexaUrlCore<8, 10> = serde_yaml::from_str(&yaml).unwrap();
+                assert_eq!(hexaurl8, deserialized);
+            }
+
+            // Unquoted scalars still reach `visit_str`.
+            let deserialized: HexaUrlCore<16, 21> = serde_yaml::from_str("12345").unwrap();
+            assert_eq!(deserialized.to_string(), "12345");
+        }
+
+        /// Tests TOML round-trips; TOML requires a table at the top level.
+        #[test]
+        fn test_serde_toml_round_trip() {
+            use std::collections::BTreeMap;
+
+            for input in ["hello", "12345", "true", "a-b"] {
+                let hexaurl = HexaUrlCore::<16, 21>::new(input).unwrap();
+                let table = BTreeMap::from([("id", hexaurl)]);
+                let toml = toml::to_string(&table).unwrap();
+                assert_eq!(toml, format!("id = \"{input}\"\n"));
+                let deserialized: BTreeMap<String, HexaUrlCore<16, 21>> =
+                    toml::from_str(&toml).unwrap();
+                assert_eq!(deserialized["id"], hexaurl);
+
+                let hexaurl8 = HexaUrlCore::<8, 10>::new(input).unwrap();
+                let toml = toml::to_string(&BTreeMap::from([("id", hexaurl8)])).unwrap();
+                let deserialized: BTreeMap<String, HexaUrlCore<8, 10>> =
+                    toml::from_str(&toml).unwrap();
+                assert_eq!(deserialized["id"], hexaurl8);
+            }
+        }
+
         /// Tests serialization in a non-human-readable context.
         #[test]
         fn test_serde_serialization_non_human_readable() {
@@ -848,6 +3762,272 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "base32")]
+    mod base32_impl {
+        use super::*;
+
+        /// Tests Crockford base32 round-trips for several sizes.
+        #[test]
+        fn test_base32_crockford_round_trip() {
+            let hexaurl = HexaUrlCore::<16, 21>::new("hello-world").unwrap();
+            let encoded = hexaurl.to_base32_crockford();
+            assert_eq!(encoded.len(), 26);
+            assert!(!encoded.contains(['I', 'L', 'O', 'U']));
+            assert_eq!(
+                HexaUrlCore::<16, 21>::from_base32_crockford(&encoded).unwrap(),
+                hexaurl
+            );
+            assert_eq!(
+                HexaUrlCore::<16, 21>::from_base32_crockford(&encoded.to_lowercase()).unwrap(),
+                hexaurl
+            );
+
+            let hexaurl = HexaUrlCore::<8, 10>::new("abcdefghij").unwrap();
+            let encoded = hexaurl.to_base32_crockford();
+            assert_eq!(encoded.len(), 13);
+            assert_eq!(
+                HexaUrlCore::<8, 10>::from_base32_crockford(&encoded).unwrap(),
+                hexaurl
+            );
+        }
+
+        /// Tests the known Crockford encoding and the ambiguous-symbol aliases.
+        #[test]
+        fn test_base32_crockford_symbols() {
+            let hexaurl = HexaUrlCore::<8, 10>::new("zzz").unwrap();
+            // 'z' is SIXBIT 58: 111010 111010 111010 -> bytes EB AE 80.
+            assert_eq!(hexaurl.to_base32_crockford(), "XEQ8000000000");
+            assert_eq!(
+                HexaUrlCore::<8, 10>::from_base32_crockford("XEQ8OOOOOOOOO").unwrap(),
+                hexaurl
+            );
+        }
+
+        /// Tests Crockford base32 decoding errors.
+        #[test]
+        fn test_base32_crockford_errors() {
+            assert_eq!(
+                HexaUrlCore::<8, 10>::from_base32_crockford("XEQ8"),
+                Err(Error::InvalidLength)
+            );
+            assert_eq!(
+                HexaUrlCore::<8, 10>::from_base32_crockford("XEQ8U00000000"),
+                Err(Error::InvalidCharacter)
+            );
+        }
+    }
+
+    #[cfg(feature = "compact-str")]
+    mod compact_str_impl {
+        use super::*;
+        use compact_str::CompactString;
+
+        /// Tests round-tripping through `CompactString`.
+        #[test]
+        fn test_compact_string_roundtrip() {
+            let hexaurl = HexaUrlCore::<16, 21>::new("Some-User").unwrap();
+            let compact = hexaurl.to_compact_string();
+            assert_eq!(compact, "some-user");
+            assert!(!compact.is_heap_allocated());
+            assert_eq!(HexaUrlCore::<16, 21>::try_from(compact).unwrap(), hexaurl);
+
+            let full = HexaUrlCore::<16, 21>::new("abcdefghijklmnopqrstu").unwrap();
+            assert!(!full.to_compact_string().is_heap_allocated());
+
+            assert_eq!(
+                HexaUrlCore::<16, 21>::try_from(CompactString::new("in valid")),
+                Err(Error::InvalidCharacter)
+            );
+        }
+    }
+
+    #[cfg(feature = "fixedstr")]
+    mod fixedstr_impl {
+        use super::*;
+        use fixedstr::str32;
+
+        /// Tests round-tripping through `fixedstr::str32`.
+        #[test]
+        fn test_str32_roundtrip() {
+            let hexaurl = HexaUrlCore::<16, 21>::new("user-profile").unwrap();
+            let key = str32::try_from(hexaurl).unwrap();
+            assert_eq!(key.as_str(), "user-profile");
+            assert_eq!(HexaUrlCore::<16, 21>::try_from(key).unwrap(), hexaurl);
+
+            let upper = str32::make("User-Profile");
+            assert_eq!(HexaUrlCore::<16, 21>::try_from(upper).unwrap(), hexaurl);
+        }
+
+        /// Tests rejecting `str32` values that are not valid HexaURLs.
+        #[test]
+        fn test_str32_invalid() {
+            assert_eq!(
+                HexaUrlCore::<16, 21>::try_from(str32::make("héllo")),
+                Err(Error::InvalidCharacter)
+            );
+            assert_eq!(
+                HexaUrlCore::<8, 10>::try_from(str32::make("much-too-long")),
+                Err(Error::StringTooLong(10))
+            );
+        }
+
+        /// Tests that decoded strings longer than a `str32` are rejected, not truncated.
+        #[test]
+        fn test_str32_too_long() {
+            let fits = HexaUrlCore::<32, 42>::new(&"a".repeat(31)).unwrap();
+            assert_eq!(str32::try_from(fits).unwrap().as_str(), "a".repeat(31));
+
+            let long = HexaUrlCore::<32, 42>::new(&"a".repeat(32)).unwrap();
+            assert_eq!(str32::try_from(long), Err(Error::StringTooLong(31)));
+        }
+    }
+
+    #[cfg(feature = "heapless")]
+    mod heapless_impl {
+        use super::*;
+
+        /// Tests that the heapless string matches `to_string()`.
+        #[test]
+        fn test_to_heapless() {
+            for input in ["", "a", "Some-User", "abcdefghijklmnopqrstu"] {
+                let hexaurl = HexaUrlCore::<16, 21>::new_minimal_config(input).unwrap();
+                let decoded: heapless::String<21> = hexaurl.to_heapless();
+                assert_eq!(decoded.as_str(), hexaurl.to_string());
+            }
+
+            let full = HexaUrlCore::<8, 10>::new("abcdefghij").unwrap();
+            assert_eq!(full.to_heapless(), "abcdefghij");
+        }
+    }
+
+    #[cfg(feature = "subtle")]
+    mod subtle_impl {
+        use super::*;
+        use ::subtle::ConstantTimeEq;
+
+        /// Tests that constant-time equality agrees with `==`.
+        #[test]
+        fn test_ct_eq() {
+            let a = HexaUrlCore::<16, 21>::new("token-abc").unwrap();
+            let b = HexaUrlCore::<16, 21>::new("TOKEN-ABC").unwrap();
+            let c = HexaUrlCore::<16, 21>::new("token-abd").unwrap();
+
+            assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+            assert_eq!(bool::from(a.ct_eq(&c)), a == c);
+            assert!(bool::from(a.ct_eq(&b)));
+            assert!(!bool::from(a.ct_eq(&c)));
+            assert!(bool::from(ConstantTimeEq::ct_eq(&a, &b)));
+        }
+    }
+
+    #[cfg(feature = "url")]
+    mod url_impl {
+        use super::*;
+
+        /// Tests that percent-encoded input is decoded before validation.
+        #[test]
+        fn test_new_from_url_encoded() {
+            assert_eq!(
+                HexaUrlCore::<16, 21>::new_from_url_encoded("foo%2Dbar").unwrap(),
+                HexaUrlCore::<16, 21>::new("foo-bar").unwrap()
+            );
+            assert_eq!(
+                HexaUrlCore::<16, 21>::new_from_url_encoded("plain").unwrap(),
+                "plain"
+            );
+            assert_eq!(
+                HexaUrlCore::<16, 21>::new_from_url_encoded("foo%20bar"),
+                Err(Error::InvalidCharacter)
+            );
+            assert_eq!(
+                HexaUrlCore::<16, 21>::new_from_url_encoded("foo%FFbar"),
+                Err(Error::InvalidCharacter)
+            );
+            assert_eq!(
+                HexaUrlCore::<16, 21>::new_from_url_encoded("%2Dfoo"),
+                Err(Error::LeadingTrailingHyphen)
+            );
+
+            let config = Config::<16>::minimal();
+            let hexaurl =
+                HexaUrlCore::<16, 21>::new_from_url_encoded_with_config("%5Fa", &config).unwrap();
+            assert_eq!(hexaurl, "_a");
+        }
+    }
+
+    #[cfg(feature = "json")]
+    mod json {
+        use super::*;
+
+        /// Tests that JSON string literals round trip and escapes are rejected.
+        #[test]
+        fn test_json_string() {
+            let hexaurl = HexaUrlCore::<16, 21>::new("Foo-Bar").unwrap();
+            assert_eq!(hexaurl.to_json_string(), "\"foo-bar\"");
+            assert_eq!(
+                HexaUrlCore::<16, 21>::from_json_string(&hexaurl.to_json_string()),
+                Ok(hexaurl)
+            );
+
+            for invalid in [
+                "foo",
+                "\"foo",
+                "foo\"",
+                "\"",
+                "\"foo\\u002Dbar\"",
+                "\"a\\\"\"",
+            ] {
+                assert_eq!(
+                    HexaUrlCore::<16, 21>::from_json_string(invalid),
+                    Err(Error::InvalidCharacter)
+                );
+            }
+            assert_eq!(
+                HexaUrlCore::<16, 21>::from_json_string("\"-foo\""),
+                Err(Error::LeadingTrailingHyphen)
+            );
+        }
+    }
+
+    #[cfg(feature = "similarity")]
+    mod similarity {
+        use crate::struct_api::HexaUrl;
+
+        #[test]
+        fn test_approx_similarity() {
+            let a = HexaUrl::new("user-profile").unwrap();
+            assert_eq!(a.approx_similarity(&a), 1.0);
+
+            let empty = HexaUrl::new_minimal_config("").unwrap();
+            assert_eq!(empty.approx_similarity(&empty), 1.0);
+            assert_eq!(a.approx_similarity(&empty), 0.0);
+
+            // The first chunk ("user") is shared, the rest differs.
+            let b = HexaUrl::new("user-account").unwrap();
+            assert_eq!(a.approx_similarity(&b), 3.0 / 9.0);
+            assert_eq!(a.approx_similarity(&b), b.approx_similarity(&a));
+
+            for other in ["user-profiles", "user-profil", "admin-panel"] {
+                let score = a.approx_similarity(&HexaUrl::new(other).unwrap());
+                assert!((0.0..1.0).contains(&score), "{other}");
+            }
+        }
+
+        #[test]
+        fn test_most_similar() {
+            let target = HexaUrl::new("team-alpha").unwrap();
+            let candidates = ["billing", "team-alpah", "team-beta", "team-alpha"]
+                .map(|s| HexaUrl::new(s).unwrap());
+            assert_eq!(target.most_similar(&candidates), Some(&candidates[3]));
+            assert_eq!(target.most_similar(&candidates[..3]), Some(&candidates[1]));
+            assert_eq!(target.most_similar(&[]), None);
+
+            // Equal scores keep the first candidate.
+            let ties = ["xyz", "xyz"].map(|s| HexaUrl::new(s).unwrap());
+            assert!(std::ptr::eq(target.most_similar(&ties).unwrap(), &ties[0]));
+        }
+    }
+
     #[cfg(feature = "candid")]
     mod candid_impl {
         use super::HexaUrlCore;
@@ -0,0 +1,143 @@
+//! Exact-Case HexaURL
+//!
+//! A companion to [`HexaUrlCore`] that keeps the original input next to the packed bytes,
+//! for identifiers that must be displayed exactly as entered while still being keyed
+//! case-insensitively.
+
+use super::core::HexaUrlCore;
+use crate::Error;
+use hexaurl_config::Config;
+use std::fmt;
+
+/// A HexaURL that also stores the original input string.
+///
+/// Equality, ordering and hashing use the packed bytes only, so values differing only in
+/// case are equal and behave as the same map key. [`Display`](fmt::Display) writes the
+/// original string instead of the lowercase decoding.
+///
+/// `HexaUrlExact` borrows as [`HexaUrlCore`], so maps keyed by it can be queried with a plain
+/// HexaURL.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::struct_api::{HexaUrl16, HexaUrlExact};
+///
+/// let a = HexaUrlExact::<16, 21>::new("Some-User").unwrap();
+/// let b = HexaUrlExact::<16, 21>::new("some-user").unwrap();
+///
+/// assert_eq!(a, b);
+/// assert_eq!(a.to_string(), "Some-User");
+/// assert_eq!(a.as_hexaurl(), &HexaUrl16::new("some-user").unwrap());
+/// ```
+#[derive(Clone, Debug)]
+pub struct HexaUrlExact<const N: usize, const S: usize> {
+    url: HexaUrlCore<N, S>,
+    original: Box<str>,
+}
+
+impl<const N: usize, const S: usize> HexaUrlExact<N, S> {
+    /// Encodes the input string using the default validation rules and keeps a copy of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` under the same conditions as [`HexaUrlCore::new`].
+    #[inline]
+    pub fn new(input: &str) -> Result<Self, Error> {
+        Ok(Self {
+            url: HexaUrlCore::new(input)?,
+            original: input.into(),
+        })
+    }
+
+    /// Encodes the input string using a custom validation configuration and keeps a copy of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` under the same conditions as [`HexaUrlCore::new_with_config`].
+    #[inline]
+    pub fn new_with_config(input: &str, config: &Config<N>) -> Result<Self, Error> {
+        Ok(Self {
+            url: HexaUrlCore::new_with_config(input, config)?,
+            original: input.into(),
+        })
+    }
+
+    /// Returns the packed HexaURL.
+    #[inline]
+    pub const fn as_hexaurl(&self) -> &HexaUrlCore<N, S> {
+        &self.url
+    }
+
+    /// Returns the original input string.
+    #[inline]
+    pub fn as_original(&self) -> &str {
+        &self.original
+    }
+
+    /// Returns the packed bytes.
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        self.url.as_bytes()
+    }
+
+    /// Splits the value into the packed HexaURL and the original input string.
+    #[inline]
+    pub fn into_parts(self) -> (HexaUrlCore<N, S>, Box<str>) {
+        (self.url, self.original)
+    }
+}
+
+impl<const N: usize, const S: usize> From<HexaUrlExact<N, S>> for HexaUrlCore<N, S> {
+    #[inline]
+    fn from(exact: HexaUrlExact<N, S>) -> Self {
+        exact.url
+    }
+}
+
+impl_hexaurl_key!(HexaUrlExact);
+
+impl<const N: usize, const S: usize> fmt::Display for HexaUrlExact<N, S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.original)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::struct_api::HexaUrl;
+    use std::collections::HashMap;
+
+    type Exact = HexaUrlExact<16, 21>;
+
+    #[test]
+    fn test_exact_display() {
+        let exact = Exact::new("Some-User").unwrap();
+        assert_eq!(exact.to_string(), "Some-User");
+        assert_eq!(exact.as_original(), "Some-User");
+        assert_eq!(exact.as_hexaurl().to_string(), "some-user");
+        assert_eq!(
+            exact.as_bytes(),
+            HexaUrl::new("some-user").unwrap().as_bytes()
+        );
+
+        let (url, original) = exact.into_parts();
+        assert_eq!(url, HexaUrl::new("some-user").unwrap());
+        assert_eq!(&*original, "Some-User");
+
+        assert_eq!(Exact::new("some user"), Err(Error::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_exact_keeps_first_original() {
+        let mut map = HashMap::new();
+        map.insert(Exact::new("Alice").unwrap(), 1);
+        map.insert(Exact::new("ALICE").unwrap(), 2);
+        assert_eq!(map.len(), 1);
+        // Replacing a value keeps the first inserted key, along with its original casing.
+        assert_eq!(map.keys().next().unwrap().to_string(), "Alice");
+        assert_eq!(map.get(&HexaUrl::new("alice").unwrap()), Some(&2));
+    }
+}
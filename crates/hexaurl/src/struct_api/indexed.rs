@@ -0,0 +1,150 @@
+//! Indexed HexaURL
+//!
+//! A map key pairing [`HexaUrlCore`] with its decoded string, for caches that look values up
+//! by the packed key but also print their keys often.
+
+use super::core::HexaUrlCore;
+use crate::Error;
+use std::{fmt, sync::Arc};
+
+/// A HexaURL that also holds its decoded string in a shared [`Arc<str>`].
+///
+/// The string is decoded once on construction, so [`as_str`](Self::as_str) and
+/// [`Display`](fmt::Display) cost no decoding. Clones share the same string allocation.
+///
+/// Equality, ordering and hashing use the packed bytes only, and `IndexedHexaUrl` borrows as
+/// [`HexaUrlCore`], so maps keyed by it can be queried with a plain HexaURL.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::struct_api::{HexaUrl16, IndexedHexaUrl};
+/// use std::collections::HashMap;
+///
+/// let mut cache = HashMap::new();
+/// cache.insert(IndexedHexaUrl::<16, 21>::new("user-alice").unwrap(), 1);
+///
+/// assert_eq!(cache.get(&HexaUrl16::new("user-alice").unwrap()), Some(&1));
+/// for key in cache.keys() {
+///     assert_eq!(key.as_str(), "user-alice");
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct IndexedHexaUrl<const N: usize, const S: usize> {
+    url: HexaUrlCore<N, S>,
+    decoded: Arc<str>,
+}
+
+impl<const N: usize, const S: usize> IndexedHexaUrl<N, S> {
+    /// Encodes the input string using the default validation rules and stores its decoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` under the same conditions as [`HexaUrlCore::new`].
+    #[inline]
+    pub fn new(input: &str) -> Result<Self, Error> {
+        HexaUrlCore::new(input).map(Self::from_hexaurl)
+    }
+
+    /// Decodes `url` and pairs it with the decoded string.
+    #[inline]
+    pub fn from_hexaurl(url: HexaUrlCore<N, S>) -> Self {
+        let decoded = url.decode_unchecked_into(&mut [0; S]).into();
+        Self { url, decoded }
+    }
+
+    /// Returns the packed HexaURL.
+    #[inline]
+    pub const fn as_hexaurl(&self) -> &HexaUrlCore<N, S> {
+        &self.url
+    }
+
+    /// Returns the decoded string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.decoded
+    }
+
+    /// Returns a shared handle to the decoded string, without copying it.
+    #[inline]
+    pub fn shared_str(&self) -> Arc<str> {
+        Arc::clone(&self.decoded)
+    }
+
+    /// Returns the packed bytes.
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        self.url.as_bytes()
+    }
+}
+
+impl<const N: usize, const S: usize> From<HexaUrlCore<N, S>> for IndexedHexaUrl<N, S> {
+    #[inline]
+    fn from(url: HexaUrlCore<N, S>) -> Self {
+        Self::from_hexaurl(url)
+    }
+}
+
+impl<const N: usize, const S: usize> From<IndexedHexaUrl<N, S>> for HexaUrlCore<N, S> {
+    #[inline]
+    fn from(indexed: IndexedHexaUrl<N, S>) -> Self {
+        indexed.url
+    }
+}
+
+impl<const N: usize, const S: usize> AsRef<str> for IndexedHexaUrl<N, S> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.decoded
+    }
+}
+
+impl_hexaurl_key!(IndexedHexaUrl);
+
+impl<const N: usize, const S: usize> fmt::Display for IndexedHexaUrl<N, S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.decoded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::struct_api::HexaUrl;
+
+    type Indexed = IndexedHexaUrl<16, 21>;
+
+    #[test]
+    fn test_indexed_as_str() {
+        let indexed = Indexed::new("Some-User").unwrap();
+        assert_eq!(indexed.as_str(), "some-user");
+        assert_eq!(indexed.to_string(), "some-user");
+        assert_eq!(indexed.as_hexaurl(), &HexaUrl::new("some-user").unwrap());
+        assert_eq!(indexed.as_bytes(), indexed.as_hexaurl().as_bytes());
+
+        // Clones and shared handles point at the same allocation.
+        let clone = indexed.clone();
+        assert!(std::ptr::eq(clone.as_str(), indexed.as_str()));
+        assert!(Arc::ptr_eq(&indexed.shared_str(), &clone.shared_str()));
+
+        let url = HexaUrl::new_minimal_config("a_b").unwrap();
+        assert_eq!(Indexed::from(url).as_str(), "a_b");
+        assert_eq!(HexaUrl::from(Indexed::from(url)), url);
+
+        assert_eq!(Indexed::new("some user"), Err(Error::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_indexed_shared_str() {
+        let indexed = Indexed::new("Carol").unwrap();
+        let shared = indexed.shared_str();
+        assert_eq!(format!("[{indexed:>7}]"), "[  carol]");
+        assert_eq!(indexed.as_ref(), "carol");
+
+        // The shared handle outlives the key it came from.
+        drop(indexed);
+        assert_eq!(&*shared, "carol");
+        assert_eq!(Arc::strong_count(&shared), 1);
+    }
+}
@@ -0,0 +1,111 @@
+//! Map Extensions
+//!
+//! Helpers for listing the keys of maps keyed by [`HexaUrlCore`] as decoded strings,
+//! e.g. for admin or debugging endpoints.
+
+use super::core::HexaUrlCore;
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::BuildHasher,
+};
+
+/// Extension methods for maps keyed by HexaURL values.
+///
+/// Each key is decoded without validation, since map keys were validated on insertion.
+/// `BTreeMap` yields keys in byte order, which is not the lexical order of the decoded
+/// strings.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use hexaurl::{HexaUrl, struct_api::HexaUrlMapExt};
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(HexaUrl::new("alice").unwrap(), 1);
+///
+/// assert_eq!(map.decoded_keys().collect::<Vec<_>>(), ["alice"]);
+/// assert_eq!(map.decoded_iter().next(), Some(("alice".to_owned(), &1)));
+/// ```
+pub trait HexaUrlMapExt<V> {
+    /// Returns an iterator over the decoded keys.
+    fn decoded_keys(&self) -> impl Iterator<Item = String> + '_;
+
+    /// Returns an iterator over the decoded keys paired with their values.
+    fn decoded_iter<'a>(&'a self) -> impl Iterator<Item = (String, &'a V)>
+    where
+        V: 'a;
+}
+
+impl<const N: usize, const S: usize, V> HexaUrlMapExt<V> for BTreeMap<HexaUrlCore<N, S>, V> {
+    #[inline]
+    fn decoded_keys(&self) -> impl Iterator<Item = String> + '_ {
+        self.keys().map(HexaUrlCore::decode_unchecked)
+    }
+
+    #[inline]
+    fn decoded_iter<'a>(&'a self) -> impl Iterator<Item = (String, &'a V)>
+    where
+        V: 'a,
+    {
+        self.iter().map(|(k, v)| (k.decode_unchecked(), v))
+    }
+}
+
+impl<const N: usize, const S: usize, V, H: BuildHasher> HexaUrlMapExt<V>
+    for HashMap<HexaUrlCore<N, S>, V, H>
+{
+    #[inline]
+    fn decoded_keys(&self) -> impl Iterator<Item = String> + '_ {
+        self.keys().map(HexaUrlCore::decode_unchecked)
+    }
+
+    #[inline]
+    fn decoded_iter<'a>(&'a self) -> impl Iterator<Item = (String, &'a V)>
+    where
+        V: 'a,
+    {
+        self.iter().map(|(k, v)| (k.decode_unchecked(), v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::struct_api::{HexaUrl, HexaUrl8};
+
+    #[test]
+    fn test_btreemap_decoded() {
+        let mut map = BTreeMap::new();
+        for (i, key) in ["carol", "alice", "bob"].into_iter().enumerate() {
+            map.insert(HexaUrl::new(key).unwrap(), i);
+        }
+
+        let mut keys: Vec<String> = map.decoded_keys().collect();
+        keys.sort();
+        assert_eq!(keys, ["alice", "bob", "carol"]);
+
+        let mut pairs: Vec<(String, &usize)> = map.decoded_iter().collect();
+        pairs.sort();
+        assert_eq!(
+            pairs,
+            [
+                ("alice".to_owned(), &1),
+                ("bob".to_owned(), &2),
+                ("carol".to_owned(), &0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hashmap_decoded() {
+        let mut map = HashMap::new();
+        map.insert(HexaUrl8::new("Some-Key").unwrap(), "value");
+
+        assert_eq!(map.decoded_keys().collect::<Vec<_>>(), ["some-key"]);
+        assert_eq!(
+            map.decoded_iter().collect::<Vec<_>>(),
+            [("some-key".to_owned(), &"value")]
+        );
+    }
+}
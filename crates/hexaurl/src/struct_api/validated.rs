@@ -0,0 +1,120 @@
+//! Validated HexaURL
+//!
+//! A typestate wrapper marking a [`HexaUrlCore`] as checked against a configuration, so
+//! code that requires a policy can take it by type instead of re-validating.
+
+use super::core::HexaUrlCore;
+use crate::Error;
+use hexaurl_config::Config;
+use std::{fmt, ops::Deref};
+
+/// A [`HexaUrlCore`] whose decoded string has passed validation against a [`Config`].
+///
+/// It can only be built through [`validate`](Self::validate) or
+/// [`HexaUrlCore::apply_config_validation`], and dereferences to the wrapped value. The type
+/// does not record which configuration was applied.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexaurl::{config::Config, struct_api::ValidatedHexaUrl, HexaUrl};
+///
+/// let untrusted = HexaUrl::new_minimal_config("ab").unwrap();
+/// assert!(ValidatedHexaUrl::validate(untrusted, &Config::default()).is_err());
+///
+/// let validated = HexaUrl::new_minimal_config("abc")
+///     .unwrap()
+///     .apply_config_validation(&Config::default())
+///     .unwrap();
+/// assert_eq!(validated.len(), 3);
+/// ```
+#[must_use]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValidatedHexaUrl<const N: usize, const S: usize>(HexaUrlCore<N, S>);
+
+impl<const N: usize, const S: usize> ValidatedHexaUrl<N, S> {
+    /// Validates the decoded string of `hex` against `config` and wraps it.
+    ///
+    /// The string is decoded into a stack buffer, so nothing is allocated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the decoded string fails validation under `config`.
+    #[inline]
+    pub fn validate(hex: HexaUrlCore<N, S>, config: &Config<N>) -> Result<Self, Error> {
+        let mut dst = [0; S];
+        hex.decode_into_with_config(&mut dst, config)?;
+        Ok(Self(hex))
+    }
+
+    /// Returns the wrapped value.
+    #[inline]
+    pub const fn into_inner(self) -> HexaUrlCore<N, S> {
+        self.0
+    }
+}
+
+impl<const N: usize, const S: usize> HexaUrlCore<N, S> {
+    /// Validates this value against `config`, returning it as a [`ValidatedHexaUrl`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the decoded string fails validation under `config`.
+    #[inline]
+    pub fn apply_config_validation(
+        self,
+        config: &Config<N>,
+    ) -> Result<ValidatedHexaUrl<N, S>, Error> {
+        ValidatedHexaUrl::validate(self, config)
+    }
+}
+
+impl<const N: usize, const S: usize> Deref for ValidatedHexaUrl<N, S> {
+    type Target = HexaUrlCore<N, S>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize, const S: usize> From<ValidatedHexaUrl<N, S>> for HexaUrlCore<N, S> {
+    #[inline]
+    fn from(validated: ValidatedHexaUrl<N, S>) -> Self {
+        validated.0
+    }
+}
+
+impl<const N: usize, const S: usize> fmt::Display for ValidatedHexaUrl<N, S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::struct_api::HexaUrl;
+    use hexaurl_config::Composition;
+
+    #[test]
+    fn test_validate() {
+        let hex = HexaUrl::new_minimal_config("foo_bar").unwrap();
+        assert_eq!(
+            ValidatedHexaUrl::validate(hex, &Config::default()),
+            Err(Error::InvalidCharacter)
+        );
+
+        let config = Config::builder()
+            .composition(Composition::AlphanumericHyphenUnderscore)
+            .build()
+            .unwrap();
+        let validated = hex.apply_config_validation(&config).unwrap();
+        assert_eq!(*validated, hex);
+        assert_eq!(validated.to_string(), "foo_bar");
+        assert_eq!(validated.encoded_segment_count(b'_'), 2);
+        assert_eq!(HexaUrl::from(validated), hex);
+        assert_eq!(validated.into_inner(), hex);
+    }
+}